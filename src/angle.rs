@@ -0,0 +1,86 @@
+//! Trigonometric functions on values measured in radians.
+//!
+//! Angles are dimensionless so `rad` is equal to a unitless value when comparing,
+//! but the trigonometric functions require the value to actually be written in radians
+//! (e.g. `DerivedValue::radians(1.0)` or a value converted from `scaled::DEGREE`).
+
+use crate::derived::{self, DerivedUnit, DerivedValue, UnitMismatch};
+
+fn is_angle(unit: DerivedUnit) -> bool {
+    unit.radian == 1 && unit.steradian == 0 && unit.to_base() == crate::base::UNITLESS
+}
+
+impl DerivedValue<f64> {
+    fn check_angle(&self) -> Result<f64, UnitMismatch<i8>> {
+        if is_angle(self.unit) {
+            Ok(self.number)
+        } else {
+            Err(UnitMismatch {
+                lhs: self.unit,
+                rhs: derived::RADIAN,
+            })
+        }
+    }
+
+    /// The sine of an angle, failing if the value isn't in radians.
+    pub fn sin(&self) -> Result<Self, UnitMismatch<i8>> {
+        let angle = self.check_angle()?;
+        Ok(Self::new(angle.sin(), derived::UNITLESS))
+    }
+
+    /// The cosine of an angle, failing if the value isn't in radians.
+    pub fn cos(&self) -> Result<Self, UnitMismatch<i8>> {
+        let angle = self.check_angle()?;
+        Ok(Self::new(angle.cos(), derived::UNITLESS))
+    }
+
+    /// The angle in radians of the point `(x, self)` like `f64::atan2`,
+    /// failing if the coordinates have different units.
+    pub fn atan2(&self, x: &Self) -> Result<Self, UnitMismatch<i8>> {
+        if self.unit != x.unit {
+            return Err(UnitMismatch {
+                lhs: self.unit,
+                rhs: x.unit,
+            });
+        }
+        Ok(Self::radians(self.number.atan2(x.number)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use crate::scaled;
+
+    #[test]
+    fn test_trig() {
+        let angle = DerivedValue::from_unit(30.0, &scaled::DEGREE);
+        let sin = angle.sin().unwrap();
+        assert!((*sin.number() - 0.5).abs() < 1e-12);
+        assert_eq!("Unitless", sin.unit().to_string());
+        let cos = DerivedValue::radians(PI).cos().unwrap();
+        assert!((*cos.number() + 1.0).abs() < 1e-12);
+
+        let error = DerivedValue::new(1.0, derived::UNITLESS).sin().unwrap_err();
+        assert_eq!("Unit 'Unitless' didn't match 'rad'", error.to_string());
+        let error = DerivedValue::steradians(1.0).cos().unwrap_err();
+        assert_eq!("sr", error.lhs.to_string());
+        assert!(DerivedValue::radians(1.0f64).powi(2).sin().is_err());
+    }
+
+    #[test]
+    fn test_atan2() {
+        let angle = DerivedValue::meters(1.0)
+            .atan2(&DerivedValue::meters(-1.0))
+            .unwrap();
+        assert!((angle.in_unit(&scaled::DEGREE).unwrap() - 135.0).abs() < 1e-12);
+        assert_eq!("rad", angle.unit().to_string());
+
+        let error = DerivedValue::meters(1.0)
+            .atan2(&DerivedValue::seconds(1.0))
+            .unwrap_err();
+        assert_eq!("Unit 'm' didn't match 's'", error.to_string());
+    }
+}
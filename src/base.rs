@@ -1,11 +1,14 @@
-use core::fmt;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use core::{fmt, hash::Hash};
+use std::{
+    iter::{self, Product, Sum},
+    ops::{Add, Div, DivAssign, Mul, MulAssign, Neg, Sub},
+};
 
 use thiserror::Error;
 
-use crate::exponents::UnitExponent;
+use crate::exponents::{UnitError, UnitExponent};
 
-#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BaseUnit<ExponentType = i8> {
     /// kilogram (kg)
     pub(crate) kilogram: ExponentType,
@@ -85,6 +88,103 @@ impl<ExponentType: UnitExponent> BaseUnit<ExponentType> {
         }
     }
 
+    /// The non-panicking version of `multiply`.
+    pub fn checked_multiply(self, other: Self) -> Result<Self, UnitError> {
+        Ok(Self {
+            kilogram: self
+                .kilogram
+                .checked_add(other.kilogram)
+                .ok_or(UnitError::Overflow)?,
+            meter: self
+                .meter
+                .checked_add(other.meter)
+                .ok_or(UnitError::Overflow)?,
+            second: self
+                .second
+                .checked_add(other.second)
+                .ok_or(UnitError::Overflow)?,
+            mole: self
+                .mole
+                .checked_add(other.mole)
+                .ok_or(UnitError::Overflow)?,
+            ampere: self
+                .ampere
+                .checked_add(other.ampere)
+                .ok_or(UnitError::Overflow)?,
+            kelvin: self
+                .kelvin
+                .checked_add(other.kelvin)
+                .ok_or(UnitError::Overflow)?,
+            candela: self
+                .candela
+                .checked_add(other.candela)
+                .ok_or(UnitError::Overflow)?,
+        })
+    }
+
+    /// The non-panicking version of `divide`.
+    pub fn checked_divide(self, other: Self) -> Result<Self, UnitError> {
+        Ok(Self {
+            kilogram: self
+                .kilogram
+                .checked_sub(other.kilogram)
+                .ok_or(UnitError::Overflow)?,
+            meter: self
+                .meter
+                .checked_sub(other.meter)
+                .ok_or(UnitError::Overflow)?,
+            second: self
+                .second
+                .checked_sub(other.second)
+                .ok_or(UnitError::Overflow)?,
+            mole: self
+                .mole
+                .checked_sub(other.mole)
+                .ok_or(UnitError::Overflow)?,
+            ampere: self
+                .ampere
+                .checked_sub(other.ampere)
+                .ok_or(UnitError::Overflow)?,
+            kelvin: self
+                .kelvin
+                .checked_sub(other.kelvin)
+                .ok_or(UnitError::Overflow)?,
+            candela: self
+                .candela
+                .checked_sub(other.candela)
+                .ok_or(UnitError::Overflow)?,
+        })
+    }
+
+    /// The non-panicking version of `pow`.
+    pub fn checked_pow(self, power: i8) -> Result<Self, UnitError> {
+        Ok(Self {
+            kilogram: self
+                .kilogram
+                .checked_mul(power)
+                .ok_or(UnitError::Overflow)?,
+            meter: self.meter.checked_mul(power).ok_or(UnitError::Overflow)?,
+            second: self.second.checked_mul(power).ok_or(UnitError::Overflow)?,
+            mole: self.mole.checked_mul(power).ok_or(UnitError::Overflow)?,
+            ampere: self.ampere.checked_mul(power).ok_or(UnitError::Overflow)?,
+            kelvin: self.kelvin.checked_mul(power).ok_or(UnitError::Overflow)?,
+            candela: self.candela.checked_mul(power).ok_or(UnitError::Overflow)?,
+        })
+    }
+
+    /// The non-panicking version of `root`.
+    pub fn checked_root(self, root: i8) -> Result<Self, UnitError> {
+        Ok(Self {
+            kilogram: self.kilogram.exact_div(root)?,
+            meter: self.meter.exact_div(root)?,
+            second: self.second.exact_div(root)?,
+            mole: self.mole.exact_div(root)?,
+            ampere: self.ampere.exact_div(root)?,
+            kelvin: self.kelvin.exact_div(root)?,
+            candela: self.candela.exact_div(root)?,
+        })
+    }
+
     pub(crate) fn magnitude(self) -> u16 {
         self.meter.magnitude()
             + self.second.magnitude()
@@ -99,7 +199,7 @@ impl<ExponentType: UnitExponent> BaseUnit<ExponentType> {
 // We only allow fractional exponents to be multiplied by integers
 // to keep things simple, so while raising a unit by an arbitrary exponent
 // type isn't generally allowed, it is allowed if the Self type uses integers.
-// 
+//
 // Note: this mostly exists because it's useful in converting derived units
 // where we are multiplying the number of some unit in the identity
 // (e.g. 1 Second in the Couloumb identity) by the exponent associated with
@@ -119,6 +219,27 @@ impl BaseUnit<i8> {
             candela: other * self.candela,
         }
     }
+
+    /// The non-panicking version of `int_pow`.
+    pub fn checked_int_pow<ExponentType>(
+        self,
+        other: ExponentType,
+    ) -> Result<BaseUnit<ExponentType>, UnitError>
+    where
+        ExponentType: UnitExponent,
+    {
+        Ok(BaseUnit {
+            kilogram: other
+                .checked_mul(self.kilogram)
+                .ok_or(UnitError::Overflow)?,
+            meter: other.checked_mul(self.meter).ok_or(UnitError::Overflow)?,
+            second: other.checked_mul(self.second).ok_or(UnitError::Overflow)?,
+            mole: other.checked_mul(self.mole).ok_or(UnitError::Overflow)?,
+            ampere: other.checked_mul(self.ampere).ok_or(UnitError::Overflow)?,
+            kelvin: other.checked_mul(self.kelvin).ok_or(UnitError::Overflow)?,
+            candela: other.checked_mul(self.candela).ok_or(UnitError::Overflow)?,
+        })
+    }
 }
 
 impl<ExponentType: UnitExponent> Mul for BaseUnit<ExponentType> {
@@ -143,7 +264,37 @@ pub struct BaseValue<Number, ExponentType = i8> {
     pub(crate) number: Number,
 }
 
-#[derive(Error, Debug)]
+impl<Number, ExponentType> BaseValue<Number, ExponentType> {
+    pub const fn new(number: Number, unit: BaseUnit<ExponentType>) -> Self {
+        Self { unit, number }
+    }
+
+    pub const fn number(&self) -> &Number {
+        &self.number
+    }
+
+    pub const fn unit(&self) -> &BaseUnit<ExponentType> {
+        &self.unit
+    }
+
+    /// Splits the value into its number and unit.
+    pub fn into_parts(self) -> (Number, BaseUnit<ExponentType>) {
+        (self.number, self.unit)
+    }
+
+    /// Transforms the number while keeping the unit (e.g. to change its type).
+    pub fn map_number<Output>(
+        self,
+        f: impl FnOnce(Number) -> Output,
+    ) -> BaseValue<Output, ExponentType> {
+        BaseValue {
+            unit: self.unit,
+            number: f(self.number),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
 #[error("Unit '{lhs}' didn't match '{rhs}'")]
 pub struct UnitMismatch<ExponentType>
 where
@@ -170,6 +321,17 @@ where
 {
 }
 
+impl<ExponentType, Number> Hash for BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent + Hash,
+    Number: Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.unit.hash(state);
+        self.number.hash(state);
+    }
+}
+
 impl<ExponentType, Number> fmt::Debug for BaseValue<Number, ExponentType>
 where
     ExponentType: UnitExponent,
@@ -271,6 +433,114 @@ where
     }
 }
 
+impl<ExponentType, Number> MulAssign for BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: MulAssign,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        self.unit = self.unit * rhs.unit;
+        self.number *= rhs.number;
+    }
+}
+
+impl<ExponentType, Number> DivAssign for BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: DivAssign,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        self.unit = self.unit / rhs.unit;
+        self.number /= rhs.number;
+    }
+}
+
+impl<ExponentType, Number> BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// Adds up the values in `unit`, failing on the first value whose unit doesn't match.
+    /// An empty iterator sums to zero in `unit`.
+    pub fn try_sum<I>(
+        unit: BaseUnit<ExponentType>,
+        values: I,
+    ) -> Result<Self, UnitMismatch<ExponentType>>
+    where
+        I: IntoIterator<Item = Self>,
+        Number: Add<Output = Number> + Default,
+    {
+        let zero = Self {
+            unit,
+            number: Number::default(),
+        };
+        values.into_iter().try_fold(zero, |sum, value| sum + value)
+    }
+
+    /// Multiplies the values, failing if a unit exponent leaves its range.
+    /// An empty iterator multiplies to a unitless one.
+    pub fn try_product<I>(values: I) -> Result<Self, UnitError>
+    where
+        I: IntoIterator<Item = Self>,
+        Number: Mul<Output = Number> + Product,
+    {
+        let one = Self {
+            unit: BaseUnit::unitless(),
+            number: iter::empty::<Number>().product(),
+        };
+        values
+            .into_iter()
+            .try_fold(one, |product, value| product.checked_multiply(value))
+    }
+}
+
+/// Sums values into a `Result` (e.g. `values.into_iter().sum::<Result<_, _>>()`)
+/// in the unit of the first value, failing on the first value whose unit doesn't match.
+/// An empty iterator has no unit to sum in and gives a unitless zero,
+/// so use `try_sum` with the expected unit when the iterator may be empty.
+impl<ExponentType, Number> Sum<BaseValue<Number, ExponentType>>
+    for Result<BaseValue<Number, ExponentType>, UnitMismatch<ExponentType>>
+where
+    ExponentType: UnitExponent,
+    Number: Add<Output = Number> + Default,
+{
+    fn sum<I: Iterator<Item = BaseValue<Number, ExponentType>>>(mut iter: I) -> Self {
+        let Some(first) = iter.next() else {
+            return Ok(BaseValue {
+                unit: BaseUnit::unitless(),
+                number: Number::default(),
+            });
+        };
+        iter.try_fold(first, |sum, value| sum + value)
+    }
+}
+
+impl<ExponentType, Number> BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// The non-panicking version of `*`.
+    pub fn checked_multiply(self, rhs: Self) -> Result<Self, UnitError>
+    where
+        Number: Mul<Output = Number>,
+    {
+        Ok(Self {
+            unit: self.unit.checked_multiply(rhs.unit)?,
+            number: self.number * rhs.number,
+        })
+    }
+
+    /// The non-panicking version of `/`.
+    pub fn checked_divide(self, rhs: Self) -> Result<Self, UnitError>
+    where
+        Number: Div<Output = Number>,
+    {
+        Ok(Self {
+            unit: self.unit.checked_divide(rhs.unit)?,
+            number: self.number / rhs.number,
+        })
+    }
+}
+
 pub const UNITLESS: BaseUnit = BaseUnit {
     meter: 0,
     second: 0,
@@ -437,6 +707,12 @@ pub const HENRY: BaseUnit = BaseUnit {
     ..UNITLESS
 };
 
+/// lumen (lm), which is cd⋅sr but steradians are dimensionless
+pub const LUMEN: BaseUnit = BaseUnit {
+    candela: 1,
+    ..UNITLESS
+};
+
 /// lux (lx)
 pub const LUX: BaseUnit = BaseUnit {
     meter: -2,
@@ -575,4 +851,89 @@ mod tests {
 
         assert_eq!(KATAL, MOLE / SECOND);
     }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(
+            Ok(NEWTON),
+            KILOGRAM.checked_multiply(METER / (SECOND * SECOND))
+        );
+        assert_eq!(Ok(HERTZ), UNITLESS.checked_divide(SECOND));
+        assert_eq!(Ok(METER_SQ), METER.checked_pow(2));
+        assert_eq!(Ok(METER), METER_SQ.checked_root(2));
+
+        let big = METER.pow(100);
+        assert_eq!(Err(UnitError::Overflow), big.checked_multiply(big));
+        assert_eq!(
+            Err(UnitError::Overflow),
+            UNITLESS
+                .checked_divide(big)
+                .and_then(|u| u.checked_divide(big))
+        );
+        assert_eq!(Err(UnitError::Overflow), big.checked_pow(2));
+        assert_eq!(
+            Err(UnitError::InexactRoot { root: 2 }),
+            METER.checked_root(2)
+        );
+        assert_eq!(Err(UnitError::DivisionByZero), METER.checked_root(0));
+
+        let area = BaseValue {
+            unit: METER_SQ,
+            number: 6.0,
+        };
+        let length = BaseValue {
+            unit: METER,
+            number: 2.0,
+        };
+        assert_eq!(
+            Ok(BaseValue {
+                unit: METER,
+                number: 3.0
+            }),
+            area.checked_divide(length)
+        );
+        let big_value = BaseValue {
+            unit: big,
+            number: 1.0,
+        };
+        assert_eq!(
+            Err(UnitError::Overflow),
+            big_value.checked_multiply(big_value)
+        );
+    }
+
+    #[test]
+    fn test_parts() {
+        let speed = BaseValue::new(3.0, METER / SECOND);
+        assert_eq!(3.0, *speed.number());
+        assert_eq!(METER / SECOND, *speed.unit());
+        assert_eq!(
+            BaseValue::new(3, METER / SECOND),
+            speed.map_number(|n| n as i32)
+        );
+        assert_eq!((3.0, METER / SECOND), speed.into_parts());
+    }
+
+    #[test]
+    fn test_reductions() {
+        let lengths = [
+            BaseValue::meters(1u32),
+            BaseValue::meters(2),
+            BaseValue::meters(3),
+        ];
+        assert_eq!(Ok(BaseValue::meters(6)), BaseValue::try_sum(METER, lengths));
+        assert_eq!(Ok(BaseValue::meters(6)), lengths.into_iter().sum());
+        assert_eq!(
+            Ok(BaseValue::new(6, METER.pow(3))),
+            BaseValue::try_product(lengths)
+        );
+        assert_eq!(Ok(BaseValue::meters(0u32)), BaseValue::try_sum(METER, []));
+        let error = BaseValue::try_sum(METER, [BaseValue::meters(1), BaseValue::seconds(1)]);
+        assert!(error.is_err());
+
+        let mut value = BaseValue::meters(6);
+        value /= BaseValue::seconds(2);
+        value *= BaseValue::kilograms(2);
+        assert_eq!(BaseValue::new(6, KILOGRAM * METER / SECOND), value);
+    }
 }
@@ -0,0 +1,429 @@
+//! Tokenizing and evaluating the statements entered into the REPL.
+//!
+//! ```text
+//! statement := 'let' name '=' expr | expr
+//! expr      := term (('+' | '-') term)*
+//! term      := unary (('*' | '⋅' | '/') unary)*
+//! unary     := '-' unary | implicit
+//! implicit  := power power*          (e.g. `9.81 m` or `2 (3 s)`)
+//! power     := primary ('^' integer | superscript)*
+//! primary   := number | name '(' expr ')' | name | '(' expr ')'
+//! ```
+//!
+//! Names are looked up as variables first and then parsed as units.
+
+use std::collections::HashMap;
+
+use physical_units::{
+    derived::{DerivedValue, UnitMismatch},
+    exponents::UnitError,
+};
+use thiserror::Error;
+
+pub type Value = DerivedValue<f64>;
+
+#[derive(Error, Debug)]
+pub enum EvalError {
+    #[error("unexpected character '{character}' at column {column}")]
+    UnexpectedCharacter { character: char, column: usize },
+    #[error("expected {expected} at column {column}")]
+    Expected {
+        expected: &'static str,
+        column: usize,
+    },
+    #[error("unknown variable or unit '{0}'")]
+    UnknownName(String),
+    #[error("unknown function '{0}', the only function is 'sqrt'")]
+    UnknownFunction(String),
+    #[error("exponent {0} is out of range")]
+    ExponentOutOfRange(i64),
+    #[error(transparent)]
+    UnitMismatch(#[from] UnitMismatch<i8>),
+    #[error(transparent)]
+    Unit(#[from] UnitError),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(String),
+    Name(String),
+    /// A superscript exponent directly following a value (e.g. `s²`)
+    Superscript(i64),
+    Plus,
+    Minus,
+    Times,
+    Divide,
+    Caret,
+    Open,
+    Close,
+    Equals,
+}
+
+#[derive(Default)]
+pub struct Repl {
+    variables: HashMap<String, Value>,
+}
+
+impl Repl {
+    /// Evaluates a statement returning its value,
+    /// `let` statements also bind that value to a variable.
+    pub fn eval_line(&mut self, line: &str) -> Result<Value, EvalError> {
+        let tokens = tokenize(line)?;
+        // Values are only built with the checked operations, so their base exponents are in range,
+        // which is all that comparing, simplifying, and displaying their units needs
+        let mut evaluator = Evaluator {
+            tokens: &tokens,
+            pos: 0,
+            end_column: line.chars().count() + 1,
+            variables: &self.variables,
+        };
+        let (name, value) = evaluator.statement()?;
+        if let Some(name) = name {
+            self.variables.insert(name, value);
+        }
+        Ok(value)
+    }
+}
+
+fn superscript_digit(c: char) -> Option<i64> {
+    "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().position(|d| d == c).map(|d| d as i64)
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn tokenize(line: &str) -> Result<Vec<(usize, Token)>, EvalError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+        let start = i;
+        i += 1;
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' | '⋅' | '·' => Token::Times,
+            '/' => Token::Divide,
+            '^' => Token::Caret,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            '=' => Token::Equals,
+            c if c.is_ascii_digit() || c == '.' => {
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                // Scientific notation (e.g. 6.02e23 or 1e-3)
+                if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                    let mut j = i + 1;
+                    if j < chars.len() && (chars[j] == '-' || chars[j] == '+') {
+                        j += 1;
+                    }
+                    if j < chars.len() && chars[j].is_ascii_digit() {
+                        i = j;
+                        while i < chars.len() && chars[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+                Token::Number(chars[start..i].iter().collect())
+            }
+            c if c == '⁻' || superscript_digit(c).is_some() => {
+                let negative = c == '⁻';
+                let mut n: i64 = superscript_digit(c).unwrap_or(0);
+                let mut digits = !negative as usize;
+                while let Some(d) = chars.get(i).copied().and_then(superscript_digit) {
+                    n = n.saturating_mul(10).saturating_add(d);
+                    digits += 1;
+                    i += 1;
+                }
+                if digits == 0 {
+                    return Err(EvalError::Expected {
+                        expected: "an exponent",
+                        column: i + 1,
+                    });
+                }
+                Token::Superscript(if negative { -n } else { n })
+            }
+            c if is_name_start(c) => {
+                while i < chars.len() && (is_name_start(chars[i]) || chars[i].is_ascii_digit()) {
+                    i += 1;
+                }
+                Token::Name(chars[start..i].iter().collect())
+            }
+            character => return Err(EvalError::UnexpectedCharacter { character, column }),
+        };
+        tokens.push((column, token));
+    }
+    Ok(tokens)
+}
+
+struct Evaluator<'a> {
+    tokens: &'a [(usize, Token)],
+    pos: usize,
+    end_column: usize,
+    variables: &'a HashMap<String, Value>,
+}
+
+impl Evaluator<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    fn column(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map_or(self.end_column, |(column, _)| *column)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expected(&self, expected: &'static str) -> EvalError {
+        EvalError::Expected {
+            expected,
+            column: self.column(),
+        }
+    }
+
+    fn statement(&mut self) -> Result<(Option<String>, Value), EvalError> {
+        let mut name = None;
+        if self.peek() == Some(&Token::Name("let".into())) {
+            self.pos += 1;
+            match self.peek() {
+                Some(Token::Name(variable)) => name = Some(variable.clone()),
+                _ => return Err(self.expected("a variable name")),
+            }
+            self.pos += 1;
+            if !self.eat(&Token::Equals) {
+                return Err(self.expected("'='"));
+            }
+        }
+        let value = self.expr()?;
+        if self.peek().is_some() {
+            return Err(self.expected("an operator or the end of the line"));
+        }
+        Ok((name, value))
+    }
+
+    fn expr(&mut self) -> Result<Value, EvalError> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat(&Token::Plus) {
+                value = (value + self.term()?)?;
+            } else if self.eat(&Token::Minus) {
+                value = (value - self.term()?)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Value, EvalError> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat(&Token::Times) {
+                value = value.checked_multiply(self.unary()?)?;
+            } else if self.eat(&Token::Divide) {
+                value = value.checked_divide(self.unary()?)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Value, EvalError> {
+        if self.eat(&Token::Minus) {
+            Ok(-self.unary()?)
+        } else {
+            self.implicit()
+        }
+    }
+
+    fn implicit(&mut self) -> Result<Value, EvalError> {
+        let mut value = self.power()?;
+        while matches!(self.peek(), Some(Token::Name(_) | Token::Open)) {
+            value = value.checked_multiply(self.power()?)?;
+        }
+        Ok(value)
+    }
+
+    fn power(&mut self) -> Result<Value, EvalError> {
+        let mut value = self.primary()?;
+        loop {
+            let exponent = match self.peek() {
+                Some(Token::Superscript(n)) => {
+                    let n = *n;
+                    self.pos += 1;
+                    n
+                }
+                Some(Token::Caret) => {
+                    self.pos += 1;
+                    self.integer()?
+                }
+                _ => return Ok(value),
+            };
+            let exponent =
+                i8::try_from(exponent).map_err(|_| EvalError::ExponentOutOfRange(exponent))?;
+            value = value.checked_pow(exponent)?;
+        }
+    }
+
+    /// An integer exponent after a `^` which may be negative or parenthesized
+    fn integer(&mut self) -> Result<i64, EvalError> {
+        let parenthesized = self.eat(&Token::Open);
+        let negative = self.eat(&Token::Minus);
+        let n = match self.peek() {
+            Some(Token::Number(text)) => text
+                .parse::<i64>()
+                .map_err(|_| self.expected("an integer exponent"))?,
+            _ => return Err(self.expected("an integer exponent")),
+        };
+        self.pos += 1;
+        if parenthesized && !self.eat(&Token::Close) {
+            return Err(self.expected("')'"));
+        }
+        Ok(if negative { -n } else { n })
+    }
+
+    fn primary(&mut self) -> Result<Value, EvalError> {
+        let column = self.column();
+        match self.peek().cloned() {
+            Some(Token::Number(text)) => {
+                self.pos += 1;
+                text.parse().map_err(|_| EvalError::Expected {
+                    expected: "a number",
+                    column,
+                })
+            }
+            Some(Token::Name(name)) => {
+                self.pos += 1;
+                if self.eat(&Token::Open) {
+                    let argument = self.expr()?;
+                    if !self.eat(&Token::Close) {
+                        return Err(self.expected("')'"));
+                    }
+                    return match name.as_str() {
+                        "sqrt" => Ok(argument.checked_sqrt()?),
+                        _ => Err(EvalError::UnknownFunction(name)),
+                    };
+                }
+                if let Some(value) = self.variables.get(&name) {
+                    return Ok(*value);
+                }
+                Value::parse_prefixed(&format!("1 {name}"))
+                    .map_err(|_| EvalError::UnknownName(name))
+            }
+            Some(Token::Open) => {
+                self.pos += 1;
+                let value = self.expr()?;
+                if !self.eat(&Token::Close) {
+                    return Err(self.expected("')'"));
+                }
+                Ok(value)
+            }
+            _ => Err(self.expected("a number, name, or '('")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(repl: &mut Repl, line: &str) -> String {
+        match repl.eval_line(line) {
+            Ok(value) => value.simplify().to_string(),
+            Err(error) => format!("error: {error}"),
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut repl = Repl::default();
+        assert_eq!("3", eval(&mut repl, "1 + 2"));
+        assert_eq!("7", eval(&mut repl, "1 + 2 * 3"));
+        assert_eq!("-4", eval(&mut repl, "-2^2"));
+        assert_eq!("0.001", eval(&mut repl, "1e-3"));
+        assert_eq!("19.62 N", eval(&mut repl, "2 kg * 9.81 m/s^2"));
+        assert_eq!("19.62 N", eval(&mut repl, "2 kg * 9.81 m/s²"));
+        assert_eq!("5 m/s", eval(&mut repl, "10 m / 2 s"));
+        assert_eq!("4 m", eval(&mut repl, "sqrt(16 m^2)"));
+        assert_eq!("0.5 /s", eval(&mut repl, "1 / 2 s"));
+        assert_eq!("0.25 /s²", eval(&mut repl, "(2 s)^(-2)"));
+        assert_eq!("3000 m", eval(&mut repl, "3 km"));
+        assert_eq!("0.005 kg", eval(&mut repl, "5 g"));
+    }
+
+    #[test]
+    fn test_variables() {
+        let mut repl = Repl::default();
+        assert_eq!("3 m", eval(&mut repl, "let x = 3 m"));
+        assert_eq!("9 m²", eval(&mut repl, "x * x"));
+        assert_eq!("6 m", eval(&mut repl, "2 x"));
+        assert_eq!("9 m", eval(&mut repl, "let x = x + 6 m"));
+        assert_eq!("9 m", eval(&mut repl, "x"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut repl = Repl::default();
+        assert_eq!(
+            "error: Unit 'm' didn't match 's'",
+            eval(&mut repl, "1 m + 2 s")
+        );
+        assert_eq!(
+            "error: unknown variable or unit 'meters'",
+            eval(&mut repl, "3 meters")
+        );
+        assert_eq!("error: expected ')' at column 7", eval(&mut repl, "(1 + 2"));
+        assert_eq!(
+            "error: unexpected character '$' at column 3",
+            eval(&mut repl, "1 $")
+        );
+        assert_eq!(
+            "error: root 2 of the unit leaves a remainder",
+            eval(&mut repl, "sqrt(2 m)")
+        );
+        assert_eq!(
+            "error: unit exponent is out of range",
+            eval(&mut repl, "(1 m)^100 * (1 m)^100")
+        );
+        // The named exponent fits but the base exponents don't
+        assert_eq!(
+            "error: unit exponent is out of range",
+            eval(&mut repl, "(1 N)^100 * (1 N)^20")
+        );
+        assert_eq!(
+            "error: unit exponent is out of range",
+            eval(&mut repl, "(1 N)^100")
+        );
+        assert_eq!(
+            "error: unit exponent is out of range",
+            eval(&mut repl, "(1 N)^60 / (1 N)^-60")
+        );
+        assert_eq!("1 Pa³²⋅W⁴⋅Gy¹²/kg³⁶", eval(&mut repl, "(1 Hz)^100"));
+        assert_eq!("1 kg⁴⁰⋅m⋅J¹³/Pa¹³", eval(&mut repl, "(1 kg m)^40"));
+        // Exponents near the limits can still be simplified and displayed
+        assert_eq!("1 kg⁴¹⋅m/(Pa⁴¹⋅Gy²¹)", eval(&mut repl, "(1 s)^124"));
+        assert_eq!("1 J⁴²/Pa⁴²", eval(&mut repl, "(1 m)^126"));
+        assert_eq!("1 /kg¹²⁸", eval(&mut repl, "(1 kg)^-128"));
+        assert_eq!(
+            "error: exponent 200 is out of range",
+            eval(&mut repl, "(1 m)^200")
+        );
+        assert_eq!(
+            "error: unknown function 'cos', the only function is 'sqrt'",
+            eval(&mut repl, "cos(1)")
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! A read-eval-print loop for evaluating expressions with units.
+//!
+//! ```text
+//! > let v = 3 m / 2 s
+//! 1.5 m/s
+//! > v * 4 s
+//! 6 m
+//! ```
+
+mod eval;
+
+use std::io::{self, BufRead, IsTerminal, Write};
+
+fn main() {
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let mut repl = eval::Repl::default();
+    let mut line = String::new();
+    loop {
+        if interactive {
+            print!("> ");
+            let _ = io::stdout().flush();
+        }
+
+        line.clear();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {}
+            Err(error) => {
+                eprintln!("error: {error}");
+                break;
+            }
+        }
+
+        let line = line.trim();
+        match line {
+            "" => continue,
+            "exit" | "quit" => break,
+            _ => {}
+        }
+        match repl.eval_line(line) {
+            Ok(value) => println!("{}", value.simplify()),
+            Err(error) => println!("error: {error}"),
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Units of the centimetre–gram–second (CGS) system, including the Gaussian electromagnetic units.
+//!
+//! The mechanical units (e.g. the dyne and erg) measure the same quantities as SI units
+//! so they're scaled units which convert like any other.
+//!
+//! The Gaussian electromagnetic units aren't multiples of SI units:
+//! Coulomb's law is F = q₁q₂/r² rather than F = q₁q₂/(4πε₀r²),
+//! so charge has the dimension g^½⋅cm^{3/2}⋅s⁻¹ rather than A⋅s.
+//! Gaussian values are kept as `DerivedValue<f64, FractionalExponent>` in SI base units
+//! (e.g. 1 statC is 10^-4.5 kg^½⋅m^{3/2}⋅s⁻¹)
+//! and converted to SI units with explicit functions (e.g. `charge_to_si`)
+//! which multiply by √(4πε₀) or √(μ₀/4π).
+//!
+//! ```
+//! use physical_units::{cgs, derived::DerivedValue, exponents::FractionalExponent};
+//!
+//! let force = DerivedValue::dynes(2.0f64);
+//! assert!((force.number() - 2e-5).abs() < 1e-20);
+//!
+//! let charge = DerivedValue::<f64, FractionalExponent>::statcoulombs(1.0);
+//! let coulombs = cgs::charge_to_si(&charge).unwrap();
+//! assert!((coulombs.number() - 3.3356409519815204e-10).abs() < 1e-22);
+//! ```
+//!
+//! The conversions take μ₀ to be exactly 4π×10⁻⁷ N/A² (so 4πε₀ is 10⁷/c² F/m),
+//! as the CGS units were defined. Since 2019 μ₀ is measured in SI,
+//! but it differs from 4π×10⁻⁷ N/A² by less than 10⁻⁹.
+
+use crate::{
+    base::{self, BaseUnit},
+    constants,
+    derived::{self, DerivedUnit, DerivedValue, NamedUnit, UnitMismatch},
+    exponents::FractionalExponent,
+    scaled::{self, Factor, ScaledUnit},
+};
+
+const PASCAL_SECOND: DerivedUnit = DerivedUnit {
+    base: BaseUnit {
+        kilogram: 1,
+        meter: -1,
+        second: -1,
+        ..base::UNITLESS
+    },
+    ..derived::UNITLESS
+};
+
+const METER_SQ_PER_SECOND: DerivedUnit = DerivedUnit {
+    base: BaseUnit {
+        meter: 2,
+        second: -1,
+        ..base::UNITLESS
+    },
+    ..derived::UNITLESS
+};
+
+/// dyne (dyn), g⋅cm/s²
+pub const DYNE: ScaledUnit = ScaledUnit::new("dyn", derived::NEWTON, Factor::decimal(1, -5));
+
+/// erg (erg), dyn⋅cm
+pub const ERG: ScaledUnit = ScaledUnit::new("erg", derived::JOULE, Factor::decimal(1, -7));
+
+/// poise (P), the unit of dynamic viscosity, g/(cm⋅s)
+pub const POISE: ScaledUnit = ScaledUnit::new("P", PASCAL_SECOND, Factor::decimal(1, -1));
+
+/// stokes (St), the unit of kinematic viscosity, cm²/s
+pub const STOKES: ScaledUnit = ScaledUnit::new("St", METER_SQ_PER_SECOND, Factor::decimal(1, -4));
+
+/// The CGS units that can be parsed by symbol
+pub(crate) const UNITS: [&ScaledUnit; 4] = [&DYNE, &ERG, &POISE, &STOKES];
+
+scaled::scaled_constructors!(
+    dynes => DYNE,
+    ergs => ERG,
+    poise => POISE,
+    stokes => STOKES,
+);
+
+/// A Gaussian unit, which is a product of powers of the gram, centimetre, and second
+/// where the powers of the gram and centimetre can be halves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GaussianUnit {
+    pub symbol: &'static str,
+    /// The dimension in SI base units (e.g. kg^½⋅m^{3/2}⋅s⁻¹ for the statcoulomb)
+    pub unit: DerivedUnit<FractionalExponent>,
+}
+
+impl GaussianUnit {
+    /// The unit g^(grams/4)⋅cm^(centimeters/4)⋅s^seconds, with the powers of grams and centimetres in quarters
+    const fn new(symbol: &'static str, grams: i8, centimeters: i8, seconds: i8) -> Self {
+        let zero = FractionalExponent::from_quarters(0);
+        let base = BaseUnit {
+            kilogram: FractionalExponent::from_quarters(grams),
+            meter: FractionalExponent::from_quarters(centimeters),
+            second: FractionalExponent::from_quarters(seconds * 4),
+            mole: zero,
+            ampere: zero,
+            kelvin: zero,
+            candela: zero,
+        };
+        Self {
+            symbol,
+            unit: DerivedUnit::from_parts(base, [zero; NamedUnit::ALL.len()]),
+        }
+    }
+
+    /// The value of one of this unit in SI base units (e.g. 10^-4.5 for the statcoulomb).
+    pub fn factor(&self) -> f64 {
+        let quarters = 3 * self.unit.base.kilogram.quarters() + 2 * self.unit.base.meter.quarters();
+        10f64.powf(-f64::from(quarters) / 4.0)
+    }
+}
+
+/// statcoulomb (statC), the Gaussian unit of charge, g^½⋅cm^{3/2}⋅s⁻¹
+pub const STATCOULOMB: GaussianUnit = GaussianUnit::new("statC", 2, 6, -1);
+
+/// statampere (statA), the Gaussian unit of current, statC/s
+pub const STATAMPERE: GaussianUnit = GaussianUnit::new("statA", 2, 6, -2);
+
+/// statvolt (statV), the Gaussian unit of electric potential, erg/statC
+pub const STATVOLT: GaussianUnit = GaussianUnit::new("statV", 2, 2, -1);
+
+/// gauss (G), the Gaussian unit of magnetic flux density, g^½⋅cm^-½⋅s⁻¹
+pub const GAUSS: GaussianUnit = GaussianUnit::new("G", 2, -2, -1);
+
+impl DerivedValue<f64, FractionalExponent> {
+    /// Constructs a value from a number of a Gaussian unit (e.g. 1 statC is 10^-4.5 kg^½⋅m^{3/2}⋅s⁻¹).
+    pub fn from_gaussian(number: f64, unit: &GaussianUnit) -> Self {
+        DerivedValue::new(number * unit.factor(), unit.unit)
+    }
+
+    /// The number of a Gaussian unit this value is equal to, failing if it has a different dimension.
+    pub fn in_gaussian(
+        &self,
+        unit: &GaussianUnit,
+    ) -> Result<f64, UnitMismatch<FractionalExponent>> {
+        if self.unit != unit.unit {
+            return Err(UnitMismatch {
+                lhs: self.unit,
+                rhs: unit.unit,
+            });
+        }
+        Ok(self.number / unit.factor())
+    }
+
+    pub fn statcoulombs(number: f64) -> Self {
+        Self::from_gaussian(number, &STATCOULOMB)
+    }
+
+    pub fn statamperes(number: f64) -> Self {
+        Self::from_gaussian(number, &STATAMPERE)
+    }
+
+    pub fn statvolts(number: f64) -> Self {
+        Self::from_gaussian(number, &STATVOLT)
+    }
+
+    pub fn gauss(number: f64) -> Self {
+        Self::from_gaussian(number, &GAUSS)
+    }
+}
+
+/// The speed of light in m/s, exact by the definition of the metre
+const SPEED_OF_LIGHT: f64 = constants::SPEED_OF_LIGHT.value().number;
+
+/// √(4πε₀) in A⋅s²⋅kg^-½⋅m^{-3/2}, with 4πε₀ = 4π/(μ₀c²) = 10⁷/c²
+fn sqrt_4pi_epsilon_0() -> f64 {
+    1e7f64.sqrt() / SPEED_OF_LIGHT
+}
+
+/// √(μ₀/4π) in kg^½⋅m^½⋅s⁻¹⋅A⁻¹, with μ₀/4π = 10⁻⁷
+fn sqrt_mu_0_over_4pi() -> f64 {
+    1e-7f64.sqrt()
+}
+
+/// Multiplies a Gaussian value by `factor` to give a value in `si`,
+/// failing unless it has the dimension of `gaussian`.
+fn to_si(
+    value: &DerivedValue<f64, FractionalExponent>,
+    gaussian: &GaussianUnit,
+    si: DerivedUnit,
+    factor: f64,
+) -> Result<DerivedValue<f64>, UnitMismatch<FractionalExponent>> {
+    if value.unit != gaussian.unit {
+        return Err(UnitMismatch {
+            lhs: value.unit,
+            rhs: gaussian.unit,
+        });
+    }
+    Ok(DerivedValue::new(value.number * factor, si))
+}
+
+/// Divides an SI value by `factor` to give a value with the dimension of `gaussian`,
+/// failing unless it's in `si`.
+fn from_si(
+    value: &DerivedValue<f64>,
+    si: DerivedUnit,
+    gaussian: &GaussianUnit,
+    factor: f64,
+) -> Result<DerivedValue<f64, FractionalExponent>, UnitMismatch<i8>> {
+    if value.unit != si {
+        return Err(UnitMismatch {
+            lhs: value.unit,
+            rhs: si,
+        });
+    }
+    Ok(DerivedValue::new(value.number / factor, gaussian.unit))
+}
+
+/// Converts a Gaussian charge to coulombs: q_SI = q_G √(4πε₀),
+/// so 1 statC is 1/(10c) C ≈ 3.336×10⁻¹⁰ C (with c in m/s).
+pub fn charge_to_si(
+    charge: &DerivedValue<f64, FractionalExponent>,
+) -> Result<DerivedValue<f64>, UnitMismatch<FractionalExponent>> {
+    to_si(charge, &STATCOULOMB, derived::COULOMB, sqrt_4pi_epsilon_0())
+}
+
+/// Converts a charge in coulombs to Gaussian units: q_G = q_SI / √(4πε₀).
+pub fn charge_from_si(
+    charge: &DerivedValue<f64>,
+) -> Result<DerivedValue<f64, FractionalExponent>, UnitMismatch<i8>> {
+    from_si(charge, derived::COULOMB, &STATCOULOMB, sqrt_4pi_epsilon_0())
+}
+
+/// Converts a Gaussian current to amperes: I_SI = I_G √(4πε₀),
+/// so 1 statA is 1/(10c) A.
+pub fn current_to_si(
+    current: &DerivedValue<f64, FractionalExponent>,
+) -> Result<DerivedValue<f64>, UnitMismatch<FractionalExponent>> {
+    to_si(current, &STATAMPERE, derived::AMPERE, sqrt_4pi_epsilon_0())
+}
+
+/// Converts a current in amperes to Gaussian units: I_G = I_SI / √(4πε₀).
+pub fn current_from_si(
+    current: &DerivedValue<f64>,
+) -> Result<DerivedValue<f64, FractionalExponent>, UnitMismatch<i8>> {
+    from_si(current, derived::AMPERE, &STATAMPERE, sqrt_4pi_epsilon_0())
+}
+
+/// Converts a Gaussian electric potential to volts: V_SI = V_G / √(4πε₀),
+/// so 1 statV is c/10⁶ V ≈ 299.79 V.
+pub fn potential_to_si(
+    potential: &DerivedValue<f64, FractionalExponent>,
+) -> Result<DerivedValue<f64>, UnitMismatch<FractionalExponent>> {
+    to_si(
+        potential,
+        &STATVOLT,
+        derived::VOLT,
+        sqrt_4pi_epsilon_0().recip(),
+    )
+}
+
+/// Converts an electric potential in volts to Gaussian units: V_G = V_SI √(4πε₀).
+pub fn potential_from_si(
+    potential: &DerivedValue<f64>,
+) -> Result<DerivedValue<f64, FractionalExponent>, UnitMismatch<i8>> {
+    from_si(
+        potential,
+        derived::VOLT,
+        &STATVOLT,
+        sqrt_4pi_epsilon_0().recip(),
+    )
+}
+
+/// Converts a Gaussian magnetic flux density to teslas: B_SI = B_G √(μ₀/4π),
+/// so 1 G is 10⁻⁴ T.
+pub fn magnetic_field_to_si(
+    field: &DerivedValue<f64, FractionalExponent>,
+) -> Result<DerivedValue<f64>, UnitMismatch<FractionalExponent>> {
+    to_si(field, &GAUSS, derived::TESLA, sqrt_mu_0_over_4pi())
+}
+
+/// Converts a magnetic flux density in teslas to Gaussian units: B_G = B_SI / √(μ₀/4π).
+pub fn magnetic_field_from_si(
+    field: &DerivedValue<f64>,
+) -> Result<DerivedValue<f64, FractionalExponent>, UnitMismatch<i8>> {
+    from_si(field, derived::TESLA, &GAUSS, sqrt_mu_0_over_4pi())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{base::BaseValue, exponents::ue};
+
+    type GaussianValue = DerivedValue<f64, FractionalExponent>;
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() <= 1e-12 * expected.abs(),
+            "{expected} != {actual}"
+        );
+    }
+
+    #[test]
+    fn test_mechanical_units() {
+        assert_close(1e-5, *DerivedValue::dynes(1.0).number());
+        assert_eq!(base::NEWTON, BaseValue::dynes(1.0).unit);
+        assert_close(1e7, DerivedValue::joules(1.0).in_unit(&ERG).unwrap());
+        assert_close(0.1, *DerivedValue::poise(1.0).number());
+        assert_close(1e-4, *DerivedValue::stokes(1.0).number());
+        assert!(DerivedValue::poise(1.0).in_unit(&STOKES).is_err());
+
+        assert_eq!(DYNE, "dyn".parse().unwrap());
+        assert_eq!(POISE, "P".parse().unwrap());
+        let value: scaled::ScaledValue<f64> = "3 erg".parse().unwrap();
+        assert_eq!(DerivedValue::ergs(3.0), value.to_derived());
+    }
+
+    #[test]
+    fn test_gaussian_units() {
+        let charge = GaussianValue::statcoulombs(2.0);
+        assert_eq!(ue(3) / 2, charge.unit.base.meter);
+        assert_close(2.0 * 10f64.powf(-4.5), charge.number);
+        assert_close(2.0, charge.in_gaussian(&STATCOULOMB).unwrap());
+        assert!(charge.in_gaussian(&GAUSS).is_err());
+
+        // The statvolt is an erg per statcoulomb
+        let energy = GaussianValue::from(DerivedValue::ergs(1.0));
+        let potential = energy / GaussianValue::statcoulombs(1.0);
+        assert_close(1.0, potential.in_gaussian(&STATVOLT).unwrap());
+        // and the statampere a statcoulomb per second
+        let second = GaussianValue::from(DerivedValue::seconds(1.0));
+        let current = GaussianValue::statcoulombs(1.0) / second;
+        assert_close(1.0, current.in_gaussian(&STATAMPERE).unwrap());
+        assert_eq!("kg⁰⋅⁵/(m⁰⋅⁵⋅s)", GAUSS.unit.to_string());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let coulombs = charge_to_si(&GaussianValue::statcoulombs(1.0)).unwrap();
+        assert_eq!(derived::COULOMB, coulombs.unit);
+        assert_close(1.0 / (10.0 * SPEED_OF_LIGHT), coulombs.number);
+        let charge = charge_from_si(&coulombs).unwrap();
+        assert_close(1.0, charge.in_gaussian(&STATCOULOMB).unwrap());
+
+        let amperes = current_to_si(&GaussianValue::statamperes(1.0)).unwrap();
+        assert_close(1.0 / (10.0 * SPEED_OF_LIGHT), amperes.number);
+        let current = current_from_si(&DerivedValue::amperes(1.0)).unwrap();
+        assert_close(
+            10.0 * SPEED_OF_LIGHT,
+            current.in_gaussian(&STATAMPERE).unwrap(),
+        );
+
+        let volts = potential_to_si(&GaussianValue::statvolts(1.0)).unwrap();
+        assert_close(299.792458, volts.number);
+        let potential = potential_from_si(&volts).unwrap();
+        assert_close(1.0, potential.in_gaussian(&STATVOLT).unwrap());
+
+        let teslas = magnetic_field_to_si(&GaussianValue::gauss(1.0)).unwrap();
+        assert_eq!(derived::TESLA, teslas.unit);
+        assert_close(1e-4, teslas.number);
+        let field = magnetic_field_from_si(&DerivedValue::teslas(1.0)).unwrap();
+        assert_close(1e4, field.in_gaussian(&GAUSS).unwrap());
+
+        // Coulomb's law gives the same force in both systems
+        let r = 0.01;
+        let gaussian_force = 1.0 / (r * 100.0) / (r * 100.0);
+        let q = coulombs.number;
+        let si_force = q * q / (sqrt_4pi_epsilon_0().powi(2) * r * r);
+        assert_close(
+            gaussian_force,
+            DerivedValue::new(si_force, derived::NEWTON)
+                .in_unit(&DYNE)
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_mismatched_units() {
+        let error = charge_to_si(&GaussianValue::gauss(1.0)).unwrap_err();
+        assert_eq!(STATCOULOMB.unit, error.rhs);
+        let error = magnetic_field_from_si(&DerivedValue::volts(1.0)).unwrap_err();
+        assert_eq!("Unit 'V' didn't match 'T'", error.to_string());
+    }
+}
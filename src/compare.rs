@@ -0,0 +1,236 @@
+//! Ordering for values, which is only defined between values with the same unit.
+//!
+//! `PartialOrd` returns `None` when the units don't match,
+//! while `try_cmp`, `max`, `min`, and `clamp` return a `UnitMismatch`.
+
+use std::cmp::Ordering;
+
+use crate::{
+    base::{self, BaseValue},
+    derived::{self, DerivedValue},
+    exponents::UnitExponent,
+};
+
+macro_rules! impl_compare {
+    ($value:ident, $module:ident) => {
+        impl<Number, ExponentType> PartialOrd for $value<Number, ExponentType>
+        where
+            ExponentType: UnitExponent,
+            Number: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                if self.unit != other.unit {
+                    return None;
+                }
+                self.number.partial_cmp(&other.number)
+            }
+        }
+
+        impl<Number, ExponentType> $value<Number, ExponentType>
+        where
+            ExponentType: UnitExponent,
+        {
+            fn check_unit(&self, other: &Self) -> Result<(), $module::UnitMismatch<ExponentType>> {
+                if self.unit == other.unit {
+                    Ok(())
+                } else {
+                    Err($module::UnitMismatch {
+                        lhs: self.unit,
+                        rhs: other.unit,
+                    })
+                }
+            }
+
+            /// Compares two values with the same unit.
+            pub fn try_cmp(
+                &self,
+                other: &Self,
+            ) -> Result<Ordering, $module::UnitMismatch<ExponentType>>
+            where
+                Number: Ord,
+            {
+                self.check_unit(other)?;
+                Ok(self.number.cmp(&other.number))
+            }
+
+            /// Compares two values with the same unit
+            /// returning `None` if the numbers can't be compared (e.g. NaN).
+            pub fn try_partial_cmp(
+                &self,
+                other: &Self,
+            ) -> Result<Option<Ordering>, $module::UnitMismatch<ExponentType>>
+            where
+                Number: PartialOrd,
+            {
+                self.check_unit(other)?;
+                Ok(self.number.partial_cmp(&other.number))
+            }
+
+            /// The larger of two values with the same unit
+            /// or `self` if the numbers can't be compared (e.g. NaN).
+            pub fn max(self, other: Self) -> Result<Self, $module::UnitMismatch<ExponentType>>
+            where
+                Number: PartialOrd,
+            {
+                self.check_unit(&other)?;
+                Ok(if other.number > self.number {
+                    other
+                } else {
+                    self
+                })
+            }
+
+            /// The smaller of two values with the same unit
+            /// or `self` if the numbers can't be compared (e.g. NaN).
+            pub fn min(self, other: Self) -> Result<Self, $module::UnitMismatch<ExponentType>>
+            where
+                Number: PartialOrd,
+            {
+                self.check_unit(&other)?;
+                Ok(if other.number < self.number {
+                    other
+                } else {
+                    self
+                })
+            }
+
+            /// Restricts the value to the range `min..=max`.
+            ///
+            /// Unlike `Ord::clamp` this doesn't panic if `min > max`
+            /// and instead returns `max`.
+            pub fn clamp(
+                self,
+                min: Self,
+                max: Self,
+            ) -> Result<Self, $module::UnitMismatch<ExponentType>>
+            where
+                Number: PartialOrd,
+            {
+                self.max(min)?.min(max)
+            }
+        }
+    };
+}
+
+impl_compare!(BaseValue, base);
+impl_compare!(DerivedValue, derived);
+
+/// Compares the value against a `DerivedValue` by converting
+/// its unit to base units.
+impl<Number, ExponentType> PartialEq<DerivedValue<Number, ExponentType>>
+    for BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: PartialEq,
+{
+    fn eq(&self, other: &DerivedValue<Number, ExponentType>) -> bool {
+        self.unit == other.unit.to_base() && self.number == other.number
+    }
+}
+
+/// Compares the value against a `BaseValue` by converting
+/// its unit to base units.
+impl<Number, ExponentType> PartialEq<BaseValue<Number, ExponentType>>
+    for DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: PartialEq,
+{
+    fn eq(&self, other: &BaseValue<Number, ExponentType>) -> bool {
+        other == self
+    }
+}
+
+impl<Number, ExponentType> PartialOrd<DerivedValue<Number, ExponentType>>
+    for BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: PartialOrd,
+{
+    fn partial_cmp(&self, other: &DerivedValue<Number, ExponentType>) -> Option<Ordering> {
+        if self.unit != other.unit.to_base() {
+            return None;
+        }
+        self.number.partial_cmp(&other.number)
+    }
+}
+
+impl<Number, ExponentType> PartialOrd<BaseValue<Number, ExponentType>>
+    for DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: PartialOrd,
+{
+    fn partial_cmp(&self, other: &BaseValue<Number, ExponentType>) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_ord() {
+        let one_meter = DerivedValue::meters(1.0);
+        let two_meters = DerivedValue::meters(2.0);
+        let one_second = DerivedValue::seconds(1.0);
+        assert!(one_meter < two_meters);
+        assert_eq!(None, one_meter.partial_cmp(&one_second));
+        assert!(!one_meter.lt(&one_second));
+        assert!(!one_meter.ge(&one_second));
+
+        // Mixed units can be sorted by unit and then by number
+        let mut values = [two_meters, one_second, one_meter];
+        values.sort_by(|a: &DerivedValue<f64>, b| {
+            let by_unit = a.unit().to_base().cmp(&b.unit().to_base());
+            by_unit.then(a.number().total_cmp(b.number()))
+        });
+        assert_eq!([one_second, one_meter, two_meters], values);
+
+        let joule = DerivedValue::joules(3);
+        let newton_meter = DerivedValue::new(2, derived::NEWTON * derived::METER);
+        assert_eq!(Some(Ordering::Greater), joule.partial_cmp(&newton_meter));
+        assert_eq!(
+            None,
+            BaseValue::meters(1).partial_cmp(&BaseValue::kilograms(1))
+        );
+    }
+
+    #[test]
+    fn test_helpers() {
+        let one_meter = BaseValue::meters(1);
+        let two_meters = BaseValue::meters(2);
+        let three_meters = BaseValue::meters(3);
+        assert_eq!(Ok(Ordering::Less), one_meter.try_cmp(&two_meters));
+        assert_eq!(Ok(two_meters), one_meter.max(two_meters));
+        assert_eq!(Ok(one_meter), one_meter.min(two_meters));
+        assert_eq!(Ok(two_meters), three_meters.clamp(one_meter, two_meters));
+        assert_eq!(Ok(two_meters), one_meter.clamp(two_meters, three_meters));
+
+        let error = one_meter.try_cmp(&BaseValue::seconds(1)).unwrap_err();
+        assert_eq!(base::METER, error.lhs);
+        assert_eq!(base::SECOND, error.rhs);
+        assert!(one_meter.clamp(one_meter, BaseValue::seconds(1)).is_err());
+
+        let nan = DerivedValue::seconds(f64::NAN);
+        assert_eq!(Ok(None), nan.try_partial_cmp(&DerivedValue::seconds(1.0)));
+        assert!(
+            DerivedValue::seconds(1.0)
+                .max(DerivedValue::meters(1.0))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_base_against_derived() {
+        let base_force = BaseValue::new(2.0, base::NEWTON);
+        let force = DerivedValue::newton(2.0);
+        let larger_force = DerivedValue::newton(3.0);
+        assert_eq!(base_force, force);
+        assert_eq!(force, base_force);
+        assert!(base_force < larger_force);
+        assert!(larger_force > base_force);
+        assert_eq!(None, base_force.partial_cmp(&DerivedValue::joules(3.0)));
+    }
+}
@@ -0,0 +1,580 @@
+//! Physical constants from the CODATA recommended values, as `DerivedValue`s in SI units.
+//!
+//! Each `Constant` has its standard uncertainty (zero for the constants which are exact in the SI)
+//! and the CODATA adjustment its value comes from.
+//! The constants are the CODATA 2022 values, and `CODATA_2018` has the earlier values of the ones which changed.
+//!
+//! ```
+//! use physical_units::{constants, derived::DerivedValue};
+//!
+//! let frequency = DerivedValue::hertz(5e14);
+//! let energy = constants::PLANCK.value() * frequency;
+//! assert_eq!(physical_units::derived::JOULE, *energy.unit());
+//!
+//! assert!(constants::PLANCK.is_exact());
+//! assert!((constants::GRAVITATIONAL.relative_uncertainty() - 2.2e-5).abs() < 1e-6);
+//! ```
+
+use core::{f64::consts::PI, fmt};
+
+use crate::{
+    base::{self, BaseUnit},
+    derived::{self, DerivedUnit, DerivedValue},
+};
+
+/// A CODATA adjustment of the recommended values of the constants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Revision {
+    Codata2018,
+    Codata2022,
+}
+
+impl fmt::Display for Revision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Revision::Codata2018 => write!(f, "CODATA 2018"),
+            Revision::Codata2022 => write!(f, "CODATA 2022"),
+        }
+    }
+}
+
+/// A physical constant with its value, standard uncertainty, and where the value comes from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constant {
+    symbol: &'static str,
+    name: &'static str,
+    value: DerivedValue<f64>,
+    /// In the same unit as `value`
+    uncertainty: f64,
+    revision: Revision,
+}
+
+impl Constant {
+    pub const fn new(
+        symbol: &'static str,
+        name: &'static str,
+        value: DerivedValue<f64>,
+        uncertainty: f64,
+        revision: Revision,
+    ) -> Self {
+        Self {
+            symbol,
+            name,
+            value,
+            uncertainty,
+            revision,
+        }
+    }
+
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn value(&self) -> DerivedValue<f64> {
+        self.value
+    }
+
+    /// The standard uncertainty, in the same unit as the value.
+    pub const fn uncertainty(&self) -> DerivedValue<f64> {
+        DerivedValue::new(self.uncertainty, self.value.unit)
+    }
+
+    /// The standard uncertainty divided by the magnitude of the value.
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty / self.value.number.abs()
+    }
+
+    /// Whether the value is exact, either by the definition of the SI units
+    /// or because it's calculated from exact constants.
+    pub fn is_exact(&self) -> bool {
+        self.uncertainty == 0.0
+    }
+
+    pub const fn revision(&self) -> Revision {
+        self.revision
+    }
+}
+
+/// Writes the constant in scientific notation with its uncertainty (e.g. `G = 6.6743e-11 m³/(kg⋅s²) ± 1.5e-15`).
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = {:e} {}",
+            self.symbol, self.value.number, self.value.unit
+        )?;
+        if self.is_exact() {
+            write!(f, " (exact)")
+        } else {
+            write!(f, " ± {:e}", self.uncertainty)
+        }
+    }
+}
+
+/// A constant from CODATA 2022, with its standard uncertainty
+const fn codata_2022(
+    symbol: &'static str,
+    name: &'static str,
+    number: f64,
+    unit: DerivedUnit,
+    uncertainty: f64,
+) -> Constant {
+    Constant::new(
+        symbol,
+        name,
+        DerivedValue::new(number, unit),
+        uncertainty,
+        Revision::Codata2022,
+    )
+}
+
+/// The CODATA 2018 value of a constant which changed in CODATA 2022
+const fn codata_2018(constant: Constant, number: f64, uncertainty: f64) -> Constant {
+    Constant::new(
+        constant.symbol,
+        constant.name,
+        DerivedValue::new(number, constant.value.unit),
+        uncertainty,
+        Revision::Codata2018,
+    )
+}
+
+/// A unit made of only base units
+const fn of_base(base: BaseUnit) -> DerivedUnit {
+    DerivedUnit {
+        base,
+        ..derived::UNITLESS
+    }
+}
+
+/// speed of light in vacuum, in m/s
+pub const SPEED_OF_LIGHT: Constant = codata_2022(
+    "c",
+    "speed of light in vacuum",
+    299_792_458.0,
+    of_base(BaseUnit {
+        meter: 1,
+        second: -1,
+        ..base::UNITLESS
+    }),
+    0.0,
+);
+
+/// Planck constant, in J⋅s
+pub const PLANCK: Constant = codata_2022(
+    "h",
+    "Planck constant",
+    6.626_070_15e-34,
+    DerivedUnit {
+        joule: 1,
+        ..of_base(base::SECOND)
+    },
+    0.0,
+);
+
+/// reduced Planck constant h/2π, in J⋅s
+pub const REDUCED_PLANCK: Constant = codata_2022(
+    "ħ",
+    "reduced Planck constant",
+    PLANCK.value.number / (2.0 * PI),
+    PLANCK.value.unit,
+    0.0,
+);
+
+/// elementary charge, in C
+pub const ELEMENTARY_CHARGE: Constant = codata_2022(
+    "e",
+    "elementary charge",
+    1.602_176_634e-19,
+    derived::COULOMB,
+    0.0,
+);
+
+/// Boltzmann constant, in J/K
+pub const BOLTZMANN: Constant = codata_2022(
+    "k_B",
+    "Boltzmann constant",
+    1.380_649e-23,
+    DerivedUnit {
+        joule: 1,
+        ..of_base(BaseUnit {
+            kelvin: -1,
+            ..base::UNITLESS
+        })
+    },
+    0.0,
+);
+
+/// Avogadro constant, in mol⁻¹
+pub const AVOGADRO: Constant = codata_2022(
+    "N_A",
+    "Avogadro constant",
+    6.022_140_76e23,
+    of_base(BaseUnit {
+        mole: -1,
+        ..base::UNITLESS
+    }),
+    0.0,
+);
+
+/// Newtonian constant of gravitation, in m³/(kg⋅s²)
+pub const GRAVITATIONAL: Constant = codata_2022(
+    "G",
+    "Newtonian constant of gravitation",
+    6.674_30e-11,
+    of_base(BaseUnit {
+        kilogram: -1,
+        meter: 3,
+        second: -2,
+        ..base::UNITLESS
+    }),
+    0.000_15e-11,
+);
+
+/// vacuum electric permittivity, in F/m
+pub const VACUUM_PERMITTIVITY: Constant = codata_2022(
+    "ε_0",
+    "vacuum electric permittivity",
+    8.854_187_818_8e-12,
+    DerivedUnit {
+        farad: 1,
+        ..of_base(BaseUnit {
+            meter: -1,
+            ..base::UNITLESS
+        })
+    },
+    0.000_000_001_4e-12,
+);
+
+/// vacuum magnetic permeability, in N/A²
+pub const VACUUM_PERMEABILITY: Constant = codata_2022(
+    "μ_0",
+    "vacuum magnetic permeability",
+    1.256_637_061_27e-6,
+    DerivedUnit {
+        newton: 1,
+        ..of_base(BaseUnit {
+            ampere: -2,
+            ..base::UNITLESS
+        })
+    },
+    0.000_000_000_20e-6,
+);
+
+/// electron mass, in kg
+pub const ELECTRON_MASS: Constant = codata_2022(
+    "m_e",
+    "electron mass",
+    9.109_383_713_9e-31,
+    derived::KILOGRAM,
+    0.000_000_002_8e-31,
+);
+
+/// proton mass, in kg
+pub const PROTON_MASS: Constant = codata_2022(
+    "m_p",
+    "proton mass",
+    1.672_621_925_95e-27,
+    derived::KILOGRAM,
+    0.000_000_000_52e-27,
+);
+
+/// neutron mass, in kg
+pub const NEUTRON_MASS: Constant = codata_2022(
+    "m_n",
+    "neutron mass",
+    1.674_927_500_56e-27,
+    derived::KILOGRAM,
+    0.000_000_000_85e-27,
+);
+
+/// atomic mass constant (1/12 of the mass of carbon-12), in kg
+pub const ATOMIC_MASS: Constant = codata_2022(
+    "m_u",
+    "atomic mass constant",
+    1.660_539_068_92e-27,
+    derived::KILOGRAM,
+    0.000_000_000_52e-27,
+);
+
+/// molar gas constant N_A⋅k_B, in J/(mol⋅K)
+pub const MOLAR_GAS: Constant = codata_2022(
+    "R",
+    "molar gas constant",
+    AVOGADRO.value.number * BOLTZMANN.value.number,
+    DerivedUnit {
+        joule: 1,
+        ..of_base(BaseUnit {
+            mole: -1,
+            kelvin: -1,
+            ..base::UNITLESS
+        })
+    },
+    0.0,
+);
+
+/// Faraday constant N_A⋅e, in C/mol
+pub const FARADAY: Constant = codata_2022(
+    "F",
+    "Faraday constant",
+    AVOGADRO.value.number * ELEMENTARY_CHARGE.value.number,
+    DerivedUnit {
+        coulomb: 1,
+        ..of_base(BaseUnit {
+            mole: -1,
+            ..base::UNITLESS
+        })
+    },
+    0.0,
+);
+
+/// Stefan–Boltzmann constant 2π⁵k_B⁴/(15h³c²), in W/(m²⋅K⁴)
+pub const STEFAN_BOLTZMANN: Constant = codata_2022(
+    "σ",
+    "Stefan–Boltzmann constant",
+    {
+        let k = BOLTZMANN.value.number;
+        let h = PLANCK.value.number;
+        let c = SPEED_OF_LIGHT.value.number;
+        2.0 * PI * PI * PI * PI * PI * k * k * k * k / (15.0 * h * h * h * c * c)
+    },
+    DerivedUnit {
+        watt: 1,
+        ..of_base(BaseUnit {
+            meter: -2,
+            kelvin: -4,
+            ..base::UNITLESS
+        })
+    },
+    0.0,
+);
+
+/// fine-structure constant, which is unitless
+pub const FINE_STRUCTURE: Constant = codata_2022(
+    "α",
+    "fine-structure constant",
+    7.297_352_564_3e-3,
+    derived::UNITLESS,
+    0.000_000_001_1e-3,
+);
+
+/// Rydberg constant, in m⁻¹
+pub const RYDBERG: Constant = codata_2022(
+    "R_∞",
+    "Rydberg constant",
+    10_973_731.568_157,
+    of_base(BaseUnit {
+        meter: -1,
+        ..base::UNITLESS
+    }),
+    0.000_012,
+);
+
+/// Bohr radius, in m
+pub const BOHR_RADIUS: Constant = codata_2022(
+    "a_0",
+    "Bohr radius",
+    5.291_772_105_44e-11,
+    derived::METER,
+    0.000_000_000_82e-11,
+);
+
+pub const CONSTANTS: [Constant; 19] = [
+    SPEED_OF_LIGHT,
+    PLANCK,
+    REDUCED_PLANCK,
+    ELEMENTARY_CHARGE,
+    BOLTZMANN,
+    AVOGADRO,
+    GRAVITATIONAL,
+    VACUUM_PERMITTIVITY,
+    VACUUM_PERMEABILITY,
+    ELECTRON_MASS,
+    PROTON_MASS,
+    NEUTRON_MASS,
+    ATOMIC_MASS,
+    MOLAR_GAS,
+    FARADAY,
+    STEFAN_BOLTZMANN,
+    FINE_STRUCTURE,
+    RYDBERG,
+    BOHR_RADIUS,
+];
+
+/// The CODATA 2018 values of the constants in `CONSTANTS` whose values changed in CODATA 2022.
+/// The others (the exact constants and G) have the same values in both.
+pub const CODATA_2018: [Constant; 9] = [
+    codata_2018(
+        VACUUM_PERMITTIVITY,
+        8.854_187_812_8e-12,
+        0.000_000_001_3e-12,
+    ),
+    codata_2018(
+        VACUUM_PERMEABILITY,
+        1.256_637_062_12e-6,
+        0.000_000_000_19e-6,
+    ),
+    codata_2018(ELECTRON_MASS, 9.109_383_701_5e-31, 0.000_000_002_8e-31),
+    codata_2018(PROTON_MASS, 1.672_621_923_69e-27, 0.000_000_000_51e-27),
+    codata_2018(NEUTRON_MASS, 1.674_927_498_04e-27, 0.000_000_000_95e-27),
+    codata_2018(ATOMIC_MASS, 1.660_539_066_60e-27, 0.000_000_000_50e-27),
+    codata_2018(FINE_STRUCTURE, 7.297_352_569_3e-3, 0.000_000_001_1e-3),
+    codata_2018(RYDBERG, 10_973_731.568_160, 0.000_021),
+    codata_2018(BOHR_RADIUS, 5.291_772_109_03e-11, 0.000_000_000_80e-11),
+];
+
+/// Finds a CODATA 2022 constant by its symbol (e.g. `k_B`).
+pub fn lookup(symbol: &str) -> Option<Constant> {
+    CONSTANTS
+        .into_iter()
+        .find(|constant| constant.symbol == symbol)
+}
+
+/// Finds a constant by its symbol with its value from a CODATA revision.
+pub fn lookup_revision(symbol: &str, revision: Revision) -> Option<Constant> {
+    let constant = lookup(symbol)?;
+    match revision {
+        Revision::Codata2022 => Some(constant),
+        Revision::Codata2018 => Some(
+            CODATA_2018
+                .into_iter()
+                .find(|constant| constant.symbol == symbol)
+                .unwrap_or(Constant {
+                    revision,
+                    ..constant
+                }),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(expected: f64, actual: f64, tolerance: f64) {
+        assert!(
+            (expected - actual).abs() <= tolerance * expected.abs(),
+            "{expected} != {actual}"
+        );
+    }
+
+    #[test]
+    fn test_relations() {
+        let hbar = PLANCK.value() / DerivedValue::new(2.0 * PI, derived::UNITLESS);
+        assert_eq!(REDUCED_PLANCK.value().unit, hbar.unit);
+        assert_close(REDUCED_PLANCK.value().number, hbar.number, 1e-15);
+
+        let faraday = AVOGADRO.value() * ELEMENTARY_CHARGE.value();
+        assert_eq!(FARADAY.value().unit, faraday.unit);
+        assert_close(FARADAY.value().number, faraday.number, 1e-15);
+
+        let gas = AVOGADRO.value() * BOLTZMANN.value();
+        assert_eq!(MOLAR_GAS.value().unit, gas.unit);
+        assert_close(MOLAR_GAS.value().number, gas.number, 1e-15);
+
+        // The exact constants calculated from the others agree with CODATA's printed digits
+        for (constant, printed) in [
+            (REDUCED_PLANCK, 1.054_571_817e-34),
+            (MOLAR_GAS, 8.314_462_618),
+            (FARADAY, 96_485.332_12),
+            (STEFAN_BOLTZMANN, 5.670_374_419e-8),
+        ] {
+            assert!(constant.is_exact());
+            assert_close(printed, constant.value().number, 1e-9);
+            // but aren't truncated to them
+            assert_ne!(printed, constant.value().number, "{}", constant.name());
+        }
+
+        // ε₀μ₀c² = 1
+        let c = SPEED_OF_LIGHT.value();
+        let one = VACUUM_PERMITTIVITY.value() * VACUUM_PERMEABILITY.value() * c * c;
+        assert_eq!(derived::UNITLESS, one.unit);
+        assert_close(1.0, one.number, 3.2e-10);
+
+        // α = e²/(4πε₀ħc)
+        let e = ELEMENTARY_CHARGE.value();
+        let four_pi = DerivedValue::new(4.0 * PI, derived::UNITLESS);
+        let alpha = e * e / (four_pi * VACUUM_PERMITTIVITY.value() * REDUCED_PLANCK.value() * c);
+        assert_eq!(derived::UNITLESS, alpha.unit);
+        assert_close(FINE_STRUCTURE.value().number, alpha.number, 3.2e-10);
+    }
+
+    #[test]
+    fn test_uncertainty() {
+        assert!(SPEED_OF_LIGHT.is_exact());
+        assert!(!ELECTRON_MASS.is_exact());
+        assert_eq!(derived::KILOGRAM, ELECTRON_MASS.uncertainty().unit);
+        assert_close(3.1e-10, ELECTRON_MASS.relative_uncertainty(), 0.05);
+        assert_eq!(Revision::Codata2022, GRAVITATIONAL.revision());
+        assert_eq!("CODATA 2022", GRAVITATIONAL.revision().to_string());
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(Some(BOLTZMANN), lookup("k_B"));
+        assert_eq!(None, lookup("k"));
+        for constant in CONSTANTS {
+            assert_eq!(
+                Some(constant),
+                lookup(constant.symbol()),
+                "{}",
+                constant.name()
+            );
+        }
+        assert_eq!(
+            "e = 1.602176634e-19 C (exact)",
+            ELEMENTARY_CHARGE.to_string()
+        );
+        assert_eq!(
+            "a_0 = 5.29177210544e-11 m ± 8.2e-21",
+            BOHR_RADIUS.to_string()
+        );
+    }
+
+    #[test]
+    fn test_codata_2018() {
+        let electron = lookup_revision("m_e", Revision::Codata2018).unwrap();
+        assert_eq!(Revision::Codata2018, electron.revision());
+        assert_eq!(
+            DerivedValue::new(9.109_383_701_5e-31, derived::KILOGRAM),
+            electron.value()
+        );
+        assert_ne!(ELECTRON_MASS.value(), electron.value());
+        assert_eq!(
+            Some(ELECTRON_MASS),
+            lookup_revision("m_e", Revision::Codata2022)
+        );
+
+        // Unchanged constants have the same value in both
+        let gravitational = lookup_revision("G", Revision::Codata2018).unwrap();
+        assert_eq!(Revision::Codata2018, gravitational.revision());
+        assert_eq!(GRAVITATIONAL.value(), gravitational.value());
+        assert_eq!(GRAVITATIONAL.uncertainty(), gravitational.uncertainty());
+        assert_eq!(None, lookup_revision("k", Revision::Codata2018));
+
+        for constant in CODATA_2018 {
+            let newer = lookup(constant.symbol()).unwrap();
+            assert_eq!(newer.value().unit, constant.value().unit);
+            assert_close(newer.value().number, constant.value().number, 1e-8);
+        }
+
+        // ε₀μ₀c² = 1 and α = e²/(4πε₀ħc) hold for the 2018 values too
+        let permittivity = lookup_revision("ε_0", Revision::Codata2018)
+            .unwrap()
+            .value();
+        let permeability = lookup_revision("μ_0", Revision::Codata2018)
+            .unwrap()
+            .value();
+        let c = SPEED_OF_LIGHT.value();
+        assert_close(1.0, (permittivity * permeability * c * c).number, 3.2e-10);
+        let e = ELEMENTARY_CHARGE.value();
+        let four_pi = DerivedValue::new(4.0 * PI, derived::UNITLESS);
+        let alpha = e * e / (four_pi * permittivity * REDUCED_PLANCK.value() * c);
+        let fine_structure = lookup_revision("α", Revision::Codata2018).unwrap();
+        assert_close(fine_structure.value().number, alpha.number, 3.2e-10);
+    }
+}
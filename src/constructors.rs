@@ -1,6 +1,9 @@
 use std::ops::Mul;
 
-use crate::{base, derived};
+use crate::{
+    base, derived,
+    prefix::{PowerOfTen, Prefix, prefix_power_of_ten},
+};
 
 impl<Number> base::BaseValue<Number> {
     pub const fn kilograms(number: Number) -> Self {
@@ -138,6 +141,13 @@ impl<Number> base::BaseValue<Number> {
         }
     }
 
+    pub const fn lumens(number: Number) -> Self {
+        Self {
+            unit: base::LUMEN,
+            number,
+        }
+    }
+
     pub const fn lux(number: Number) -> Self {
         Self {
             unit: base::LUX,
@@ -226,6 +236,20 @@ impl<Number> derived::DerivedValue<Number> {
 }
 
 impl<Number> derived::DerivedValue<Number> {
+    pub const fn radians(number: Number) -> Self {
+        Self {
+            unit: derived::RADIAN,
+            number,
+        }
+    }
+
+    pub const fn steradians(number: Number) -> Self {
+        Self {
+            unit: derived::STERADIAN,
+            number,
+        }
+    }
+
     pub const fn hertz(number: Number) -> Self {
         Self {
             unit: derived::HERTZ,
@@ -310,6 +334,13 @@ impl<Number> derived::DerivedValue<Number> {
         }
     }
 
+    pub const fn lumens(number: Number) -> Self {
+        Self {
+            unit: derived::LUMEN,
+            number,
+        }
+    }
+
     pub const fn lux(number: Number) -> Self {
         Self {
             unit: derived::LUX,
@@ -386,9 +417,120 @@ where
     }
 }
 
+macro_rules! prefix_constructors {
+    ($unit:ty, $($name:ident => $prefix:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Constructs a value in ", stringify!($name), "-units of `unit`, see `prefixed`.")]
+            ///
+            /// Panics if the number can't be represented, see `checked_prefixed`.
+            pub fn $name(unit: $unit, number: Number) -> Self {
+                Self::prefixed(Prefix::$prefix, unit, number)
+            }
+        )*
+    };
+}
+
+macro_rules! all_prefix_constructors {
+    ($unit:ty) => {
+        prefix_constructors!(
+            $unit,
+            quecto => Quecto,
+            ronto => Ronto,
+            yocto => Yocto,
+            zepto => Zepto,
+            atto => Atto,
+            femto => Femto,
+            pico => Pico,
+            nano => Nano,
+            micro => Micro,
+            milli => Milli,
+            centi => Centi,
+            deci => Deci,
+            deca => Deca,
+            hecto => Hecto,
+            kilo => Kilo,
+            mega => Mega,
+            giga => Giga,
+            tera => Tera,
+            peta => Peta,
+            exa => Exa,
+            zetta => Zetta,
+            yotta => Yotta,
+            ronna => Ronna,
+            quetta => Quetta,
+        );
+    };
+}
+
+impl<Number> base::BaseValue<Number>
+where
+    Number: PowerOfTen,
+{
+    /// Constructs a value with the prefix on the first symbol of the unit,
+    /// so `BaseValue::prefixed(Prefix::Kilo, base::METER, 3.0)` is 3 km (3000 m).
+    ///
+    /// Prefixes on the kilogram apply to the gram, so `BaseValue::milli(base::KILOGRAM, 3.0)`
+    /// is 3 mg (0.000003 kg) and `BaseValue::kilo(base::KILOGRAM, 3.0)` is 3 kg.
+    ///
+    /// Panics if the number can't be represented (e.g. 3 mm as an integer number of meters),
+    /// see `checked_prefixed`.
+    pub fn prefixed(prefix: Prefix, unit: base::BaseUnit, number: Number) -> Self {
+        Self::checked_prefixed(prefix, unit, number)
+            .expect("prefixed number should be representable")
+    }
+
+    /// The non-panicking version of `prefixed`,
+    /// returning `None` if the number can't be represented.
+    pub fn checked_prefixed(prefix: Prefix, unit: base::BaseUnit, number: Number) -> Option<Self> {
+        let exponents = unit.symbol_components().map(|(n, _)| n);
+        let power = prefix_power_of_ten(prefix, &exponents);
+        Some(Self {
+            unit,
+            number: number.checked_mul_power_of_ten(power)?,
+        })
+    }
+
+    all_prefix_constructors!(base::BaseUnit);
+}
+
+impl<Number> derived::DerivedValue<Number>
+where
+    Number: PowerOfTen,
+{
+    /// Constructs a value with the prefix on the first symbol of the unit,
+    /// so `DerivedValue::prefixed(Prefix::Kilo, derived::NEWTON, 3.0)` is 3 kN (3000 N).
+    ///
+    /// Prefixes on the kilogram apply to the gram, so `DerivedValue::milli(derived::KILOGRAM, 3.0)`
+    /// is 3 mg (0.000003 kg) and `DerivedValue::kilo(derived::KILOGRAM, 3.0)` is 3 kg.
+    ///
+    /// Panics if the number can't be represented (e.g. 3 mN as an integer number of newtons),
+    /// see `checked_prefixed`.
+    pub fn prefixed(prefix: Prefix, unit: derived::DerivedUnit, number: Number) -> Self {
+        Self::checked_prefixed(prefix, unit, number)
+            .expect("prefixed number should be representable")
+    }
+
+    /// The non-panicking version of `prefixed`,
+    /// returning `None` if the number can't be represented.
+    pub fn checked_prefixed(
+        prefix: Prefix,
+        unit: derived::DerivedUnit,
+        number: Number,
+    ) -> Option<Self> {
+        let exponents = unit.symbol_components().map(|(n, _)| n);
+        let power = prefix_power_of_ten(prefix, &exponents);
+        Some(Self {
+            unit,
+            number: number.checked_mul_power_of_ten(power)?,
+        })
+    }
+
+    all_prefix_constructors!(derived::DerivedUnit);
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{base, derived};
+    use crate::{base, derived, prefix::Prefix};
 
     #[test]
     fn test_time_base_int() {
@@ -548,4 +690,62 @@ mod tests {
             derived::DerivedValue::hours(1.5f32)
         );
     }
+
+    #[test]
+    fn test_prefixes() {
+        assert_eq!(
+            derived::DerivedValue::newton(3000.0),
+            derived::DerivedValue::kilo(derived::NEWTON, 3.0)
+        );
+        assert_eq!(
+            derived::DerivedValue::volts(0.002),
+            derived::DerivedValue::milli(derived::VOLT, 2.0)
+        );
+        assert_eq!(
+            base::BaseValue::seconds(3000),
+            base::BaseValue::kilo(base::SECOND, 3)
+        );
+        // Prefixes apply to the whole first symbol including its exponent
+        assert_eq!(
+            base::BaseValue {
+                unit: base::METER_SQ,
+                number: 2e6,
+            },
+            base::BaseValue::kilo(base::METER_SQ, 2.0)
+        );
+        // Prefixes on mass apply to the gram
+        assert_eq!(
+            base::BaseValue::kilograms(3.0),
+            base::BaseValue::kilo(base::KILOGRAM, 3.0)
+        );
+        assert_eq!(
+            base::BaseValue::kilograms(3e-6),
+            base::BaseValue::milli(base::KILOGRAM, 3.0)
+        );
+        assert_eq!(
+            derived::DerivedValue::kilograms(5.0),
+            derived::DerivedValue::mega(derived::KILOGRAM, 0.005)
+        );
+    }
+
+    #[test]
+    fn test_checked_prefixes() {
+        assert_eq!(
+            Some(base::BaseValue::seconds(3000)),
+            base::BaseValue::checked_prefixed(Prefix::Kilo, base::SECOND, 3)
+        );
+        // 3 ms isn't an integer number of seconds and 10³⁰ s doesn't fit in an i32
+        assert_eq!(
+            None,
+            base::BaseValue::checked_prefixed(Prefix::Milli, base::SECOND, 3)
+        );
+        assert_eq!(
+            None,
+            derived::DerivedValue::checked_prefixed(Prefix::Quetta, derived::SECOND, 3)
+        );
+        assert_eq!(
+            Some(derived::DerivedValue::volts(0.002)),
+            derived::DerivedValue::checked_prefixed(Prefix::Milli, derived::VOLT, 2.0)
+        );
+    }
 }
@@ -1,8 +1,9 @@
 use crate::{
     base,
-    derived::{self, DerivedUnit},
-    exponents::UnitExponent,
+    derived::{self, DerivedUnit, NamedUnit},
+    exponents::{UnitError, UnitExponent},
     identities::basic_identities,
+    simplify::SimplifyOptions,
 };
 
 impl<ExponentType> From<base::BaseUnit<ExponentType>> for derived::DerivedUnit<ExponentType>
@@ -40,30 +41,32 @@ where
     ExponentType: UnitExponent,
 {
     pub fn to_base(self) -> base::BaseUnit<ExponentType> {
-        self.base
-            .multiply(base::HERTZ.int_pow(self.hertz))
-            .multiply(base::NEWTON.int_pow(self.newton))
-            .multiply(base::PASCAL.int_pow(self.pascal))
-            .multiply(base::JOULE.int_pow(self.joule))
-            .multiply(base::WATT.int_pow(self.watt))
-            .multiply(base::COULOMB.int_pow(self.coulomb))
-            .multiply(base::VOLT.int_pow(self.volt))
-            .multiply(base::FARAD.int_pow(self.farad))
-            .multiply(base::OHM.int_pow(self.ohm))
-            .multiply(base::SIEMENS.int_pow(self.siemens))
-            .multiply(base::WEBER.int_pow(self.weber))
-            .multiply(base::TESLA.int_pow(self.tesla))
-            .multiply(base::HENRY.int_pow(self.henry))
-            .multiply(base::LUX.int_pow(self.lux))
-            .multiply(base::BECQUEREL.int_pow(self.becquerel))
-            .multiply(base::GRAY.int_pow(self.gray))
-            .multiply(base::SIEVERT.int_pow(self.sievert))
-            .multiply(base::KATAL.int_pow(self.katal))
+        NamedUnit::ALL
+            .into_iter()
+            .zip(self.named_exponents())
+            .fold(self.base, |base, (named, n)| {
+                base.multiply(named.definition().base.int_pow(n))
+            })
+    }
+
+    /// The non-panicking version of `to_base`, which fails if a base exponent
+    /// is out of range (e.g. for `N¹²⁰`, which is `kg¹²⁰⋅m¹²⁰/s²⁴⁰`).
+    pub fn checked_to_base(self) -> Result<base::BaseUnit<ExponentType>, UnitError> {
+        NamedUnit::ALL
+            .into_iter()
+            .zip(self.named_exponents())
+            .try_fold(self.base, |base, (named, n)| {
+                base.checked_multiply(named.definition().base.checked_int_pow(n)?)
+            })
     }
 }
 
-impl<Number> From<base::BaseValue<Number>> for derived::DerivedValue<Number> {
-    fn from(value: base::BaseValue<Number>) -> Self {
+impl<Number, ExponentType> From<base::BaseValue<Number, ExponentType>>
+    for derived::DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    fn from(value: base::BaseValue<Number, ExponentType>) -> Self {
         derived::DerivedValue {
             unit: value.unit.to_derived(),
             number: value.number,
@@ -74,7 +77,6 @@ impl<Number> From<base::BaseValue<Number>> for derived::DerivedValue<Number> {
 impl<ExponentType, Number> base::BaseValue<Number, ExponentType>
 where
     ExponentType: UnitExponent,
-    Number: Copy,
 {
     pub fn to_derived(self) -> derived::DerivedValue<Number, ExponentType> {
         derived::DerivedValue {
@@ -84,14 +86,21 @@ where
     }
 }
 
-impl<Number> From<derived::DerivedValue<Number>> for base::BaseValue<Number> {
-    fn from(value: derived::DerivedValue<Number>) -> Self {
+impl<Number, ExponentType> From<derived::DerivedValue<Number, ExponentType>>
+    for base::BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    fn from(value: derived::DerivedValue<Number, ExponentType>) -> Self {
         value.to_base()
     }
 }
 
-impl<Number> derived::DerivedValue<Number> {
-    pub fn to_base(self) -> base::BaseValue<Number> {
+impl<Number, ExponentType> derived::DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    pub fn to_base(self) -> base::BaseValue<Number, ExponentType> {
         base::BaseValue {
             unit: self.unit.to_base(),
             number: self.number,
@@ -99,18 +108,36 @@ impl<Number> derived::DerivedValue<Number> {
     }
 }
 
-impl derived::DerivedUnit {
-    pub fn simplify(self) -> Self {
+impl<ExponentType> derived::DerivedUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// Simplifies the unit by greedily applying identities in a fixed order
+    /// while they reduce the sum of absolute exponents.
+    ///
+    /// This is faster than `simplify` but may stop at a worse form (e.g. `J/m` instead of `N`).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if applying an identity overflows an exponent, see `checked_simplify_greedy`.
+    pub fn simplify_greedy(self) -> Self {
+        self.checked_simplify_greedy()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// The non-panicking version of `simplify_greedy`.
+    pub fn checked_simplify_greedy(self) -> Result<Self, UnitError> {
+        // The identities don't change the base units so they only need checking once
+        self.checked_to_base()?;
         let mut output = self;
 
-        for identity in basic_identities() {
-            let after_mul = output.multiply(identity);
-            let after_div = output.divide(identity);
+        for identity in basic_identities().map(DerivedUnit::cast_exponents) {
+            let after_mul = output.checked_multiply_exponents(identity)?;
             if after_mul.magnitude() < output.magnitude() {
                 let mut after_mul = after_mul;
                 let mut after_mul_mag = after_mul.magnitude();
                 loop {
-                    let next = after_mul.multiply(identity);
+                    let next = after_mul.checked_multiply_exponents(identity)?;
                     let next_mag = next.magnitude();
                     if next_mag >= after_mul_mag {
                         break;
@@ -119,11 +146,14 @@ impl derived::DerivedUnit {
                     after_mul_mag = next_mag;
                 }
                 output = after_mul;
-            } else if after_div.magnitude() < output.magnitude() {
+                continue;
+            }
+            let after_div = output.checked_divide_exponents(identity)?;
+            if after_div.magnitude() < output.magnitude() {
                 let mut after_div = after_div;
                 let mut after_div_mag = after_div.magnitude();
                 loop {
-                    let next = after_div.divide(identity);
+                    let next = after_div.checked_divide_exponents(identity)?;
                     let next_mag = next.magnitude();
                     if next_mag >= after_div_mag {
                         break;
@@ -135,7 +165,28 @@ impl derived::DerivedUnit {
             }
         }
 
-        output
+        Ok(output)
+    }
+}
+
+impl<Number, ExponentType> derived::DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// Simplifies the unit of the value, see `DerivedUnit::simplify`.
+    pub fn simplify(self) -> Self {
+        derived::DerivedValue {
+            unit: self.unit.simplify(),
+            number: self.number,
+        }
+    }
+
+    /// Simplifies the unit of the value, see `DerivedUnit::simplify_with`.
+    pub fn simplify_with(self, options: &SimplifyOptions<ExponentType>) -> Self {
+        derived::DerivedValue {
+            unit: self.unit.simplify_with(options),
+            number: self.number,
+        }
     }
 }
 
@@ -178,4 +229,25 @@ mod tests {
         let expected = derived::JOULE * derived::METER;
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_fractional_simplify() {
+        use crate::exponents::FractionalExponent;
+
+        type FracUnit = derived::DerivedUnit<FractionalExponent>;
+        let base_joule =
+            FracUnit::from(derived::KILOGRAM * derived::METER_SQ / derived::SECOND.pow(2));
+        let simplified = base_joule.simplify();
+        assert_eq!(FracUnit::from(derived::JOULE), simplified);
+        assert_eq!("J", simplified.to_string());
+
+        let root_watt = FracUnit::from(derived::WATT).root(2);
+        assert_eq!("W⁰⋅⁵", root_watt.simplify().to_string());
+        let root_power = FracUnit::from(derived::JOULE / derived::SECOND).root(2);
+        assert_eq!("W⁰⋅⁵", root_power.simplify().to_string());
+
+        let value = base::BaseValue::<f64, FractionalExponent>::new(2.0, base::NEWTON.into());
+        let derived_value: derived::DerivedValue<f64, FractionalExponent> = value.into();
+        assert_eq!(value, derived_value.to_base());
+    }
 }
@@ -1,18 +1,85 @@
 //! This module lets us turn int exponents into fractional
 
-use crate::{base::BaseUnit, exponents::FractionalExponent};
+use crate::{
+    base::{BaseUnit, BaseValue},
+    derived::{DerivedUnit, DerivedValue},
+    exponents::{FractionalExponent, UnitExponent},
+};
 
+impl BaseUnit {
+    /// Converts the integer exponents to another exponent type,
+    /// panicking if they're out of its range (see `UnitExponent::from_int`).
+    pub(crate) fn cast_exponents<ExponentType: UnitExponent>(self) -> BaseUnit<ExponentType> {
+        BaseUnit {
+            kilogram: ExponentType::from_int(self.kilogram),
+            meter: ExponentType::from_int(self.meter),
+            second: ExponentType::from_int(self.second),
+            mole: ExponentType::from_int(self.mole),
+            ampere: ExponentType::from_int(self.ampere),
+            kelvin: ExponentType::from_int(self.kelvin),
+            candela: ExponentType::from_int(self.candela),
+        }
+    }
+}
+
+impl DerivedUnit {
+    /// Converts the integer exponents to another exponent type,
+    /// panicking if they're out of its range (see `UnitExponent::from_int`).
+    pub(crate) fn cast_exponents<ExponentType: UnitExponent>(self) -> DerivedUnit<ExponentType> {
+        DerivedUnit::from_parts(
+            self.base.cast_exponents(),
+            self.named_exponents().map(ExponentType::from_int),
+        )
+    }
+}
 
 impl From<BaseUnit> for BaseUnit<FractionalExponent> {
     fn from(value: BaseUnit) -> Self {
-        BaseUnit {
-          kilogram: FractionalExponent::from_int(value.kilogram),
-          meter: FractionalExponent::from_int(value.meter),
-          second: FractionalExponent::from_int(value.second),
-          mole: FractionalExponent::from_int(value.mole),
-          ampere: FractionalExponent::from_int(value.ampere),
-          kelvin: FractionalExponent::from_int(value.kelvin),
-          candela: FractionalExponent::from_int(value.candela)
+        value.cast_exponents()
+    }
+}
+
+impl From<DerivedUnit> for DerivedUnit<FractionalExponent> {
+    fn from(value: DerivedUnit) -> Self {
+        value.cast_exponents()
+    }
+}
+
+impl<Number> From<BaseValue<Number>> for BaseValue<Number, FractionalExponent> {
+    fn from(value: BaseValue<Number>) -> Self {
+        BaseValue {
+            unit: value.unit.into(),
+            number: value.number,
+        }
+    }
+}
+
+impl<Number> From<DerivedValue<Number>> for DerivedValue<Number, FractionalExponent> {
+    fn from(value: DerivedValue<Number>) -> Self {
+        DerivedValue {
+            unit: value.unit.into(),
+            number: value.number,
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{base, derived, exponents::ue};
+
+    #[test]
+    fn test_fractional_conversions() {
+        let newton = DerivedUnit::<FractionalExponent>::from(derived::NEWTON);
+        assert_eq!(ue(1), newton.newton);
+        assert_eq!(
+            BaseUnit::<FractionalExponent>::from(base::NEWTON),
+            newton.to_base()
+        );
+
+        let value = DerivedValue::<_, FractionalExponent>::from(DerivedValue::hertz(2.0));
+        assert_eq!(derived::HERTZ.cast_exponents(), value.unit);
+        let value = BaseValue::<_, FractionalExponent>::from(BaseValue::meters(3));
+        assert_eq!(base::METER.cast_exponents(), value.unit);
+    }
+}
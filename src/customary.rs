@@ -0,0 +1,284 @@
+//! US customary and imperial units, defined exactly in SI units
+//! (e.g. the international foot is exactly 0.3048 m and the avoirdupois pound is exactly 0.45359237 kg).
+//!
+//! Where the US and imperial units differ (e.g. the gallon) the imperial one has an `IMPERIAL_` name
+//! and an `imp` symbol. The units can be parsed by symbol as a `ScaledUnit` or as part of a `ScaledValue`
+//! and each has a constructor that converts to SI units.
+//!
+//! ```
+//! use physical_units::{customary, derived::DerivedValue, scaled::ScaledValue};
+//!
+//! let pressure = DerivedValue::psi(30.0f64);
+//! assert!((pressure.in_unit(&customary::PSI).unwrap() - 30.0).abs() < 1e-9);
+//! assert!((pressure.number() - 206842.718795).abs() < 1e-6);
+//!
+//! let volume: ScaledValue<f64> = "2 imp gal".parse().unwrap();
+//! assert!((volume.to_derived().number() - 0.00909218).abs() < 1e-12);
+//! ```
+
+use crate::{
+    base::{self, BaseUnit},
+    derived::{self, DerivedUnit},
+    scaled::{self, Factor, ScaledUnit},
+};
+
+pub use crate::scaled::{DEGREE_FAHRENHEIT, DEGREE_RANKINE};
+
+const CUBIC_METER: DerivedUnit = DerivedUnit {
+    base: BaseUnit {
+        meter: 3,
+        ..base::UNITLESS
+    },
+    ..derived::UNITLESS
+};
+
+const METER_PER_SECOND: DerivedUnit = DerivedUnit {
+    base: BaseUnit {
+        meter: 1,
+        second: -1,
+        ..base::UNITLESS
+    },
+    ..derived::UNITLESS
+};
+
+/// inch (in)
+pub const INCH: ScaledUnit = ScaledUnit::new("in", derived::METER, Factor::decimal(254, -4));
+
+/// foot (ft)
+pub const FOOT: ScaledUnit = ScaledUnit::new("ft", derived::METER, Factor::decimal(3048, -4));
+
+/// yard (yd)
+pub const YARD: ScaledUnit = ScaledUnit::new("yd", derived::METER, Factor::decimal(9144, -4));
+
+/// mile (mi)
+pub const MILE: ScaledUnit = ScaledUnit::new("mi", derived::METER, Factor::decimal(1609344, -3));
+
+/// acre (ac), 43560 ft²
+pub const ACRE: ScaledUnit =
+    ScaledUnit::new("ac", derived::METER_SQ, Factor::decimal(40468564224, -7));
+
+/// US gallon (gal), 231 in³
+pub const GALLON: ScaledUnit =
+    ScaledUnit::new("gal", CUBIC_METER, Factor::decimal(3785411784, -12));
+
+/// US pint (pt), ⅛ gal
+pub const PINT: ScaledUnit = ScaledUnit::new("pt", CUBIC_METER, Factor::decimal(473176473, -12));
+
+/// US fluid ounce (fl oz), 1/128 gal
+pub const FLUID_OUNCE: ScaledUnit =
+    ScaledUnit::new("fl oz", CUBIC_METER, Factor::decimal(295735295625, -16));
+
+/// imperial gallon (imp gal), 4.54609 L
+pub const IMPERIAL_GALLON: ScaledUnit =
+    ScaledUnit::new("imp gal", CUBIC_METER, Factor::decimal(454609, -8));
+
+/// imperial pint (imp pt), ⅛ imp gal
+pub const IMPERIAL_PINT: ScaledUnit =
+    ScaledUnit::new("imp pt", CUBIC_METER, Factor::decimal(56826125, -11));
+
+/// imperial fluid ounce (imp fl oz), 1/160 imp gal
+pub const IMPERIAL_FLUID_OUNCE: ScaledUnit =
+    ScaledUnit::new("imp fl oz", CUBIC_METER, Factor::decimal(284130625, -13));
+
+/// avoirdupois pound (lb)
+pub const POUND: ScaledUnit =
+    ScaledUnit::new("lb", derived::KILOGRAM, Factor::decimal(45359237, -8));
+
+/// avoirdupois ounce (oz), 1/16 lb
+pub const OUNCE: ScaledUnit =
+    ScaledUnit::new("oz", derived::KILOGRAM, Factor::decimal(28349523125, -12));
+
+/// stone (st), 14 lb
+pub const STONE: ScaledUnit =
+    ScaledUnit::new("st", derived::KILOGRAM, Factor::decimal(635029318, -8));
+
+/// short ton (sh tn), 2000 lb
+pub const SHORT_TON: ScaledUnit =
+    ScaledUnit::new("sh tn", derived::KILOGRAM, Factor::decimal(90718474, -5));
+
+/// long ton (long tn), 2240 lb
+pub const LONG_TON: ScaledUnit = ScaledUnit::new(
+    "long tn",
+    derived::KILOGRAM,
+    Factor::decimal(10160469088, -7),
+);
+
+/// pound-force (lbf), the weight of 1 lb in standard gravity (9.80665 m/s²)
+pub const POUND_FORCE: ScaledUnit =
+    ScaledUnit::new("lbf", derived::NEWTON, Factor::decimal(44482216152605, -13));
+
+/// pound-force per square inch (psi), which isn't a terminating decimal in Pa
+pub const PSI: ScaledUnit = ScaledUnit::new(
+    "psi",
+    derived::PASCAL,
+    Factor::ratio(8896443230521, 1290320000),
+);
+
+/// foot-pound (ft⋅lbf)
+pub const FOOT_POUND: ScaledUnit = ScaledUnit::new(
+    "ft⋅lbf",
+    derived::JOULE,
+    Factor::decimal(135581794833140040, -17),
+);
+
+/// International Table British thermal unit (BTU)
+pub const BTU: ScaledUnit =
+    ScaledUnit::new("BTU", derived::JOULE, Factor::decimal(105505585262, -8));
+
+/// mechanical horsepower (hp), 550 ft⋅lbf/s
+pub const HORSEPOWER: ScaledUnit =
+    ScaledUnit::new("hp", derived::WATT, Factor::decimal(74569987158227022, -14));
+
+/// mile per hour (mph)
+pub const MILE_PER_HOUR: ScaledUnit =
+    ScaledUnit::new("mph", METER_PER_SECOND, Factor::decimal(44704, -5));
+
+/// The customary units that can be parsed by symbol
+pub(crate) const UNITS: [&ScaledUnit; 22] = [
+    &INCH,
+    &FOOT,
+    &YARD,
+    &MILE,
+    &ACRE,
+    &GALLON,
+    &PINT,
+    &FLUID_OUNCE,
+    &IMPERIAL_GALLON,
+    &IMPERIAL_PINT,
+    &IMPERIAL_FLUID_OUNCE,
+    &POUND,
+    &OUNCE,
+    &STONE,
+    &SHORT_TON,
+    &LONG_TON,
+    &POUND_FORCE,
+    &PSI,
+    &FOOT_POUND,
+    &BTU,
+    &HORSEPOWER,
+    &MILE_PER_HOUR,
+];
+
+scaled::scaled_constructors!(
+    inches => INCH,
+    feet => FOOT,
+    yards => YARD,
+    miles => MILE,
+    acres => ACRE,
+    gallons => GALLON,
+    pints => PINT,
+    fluid_ounces => FLUID_OUNCE,
+    imperial_gallons => IMPERIAL_GALLON,
+    imperial_pints => IMPERIAL_PINT,
+    imperial_fluid_ounces => IMPERIAL_FLUID_OUNCE,
+    pounds => POUND,
+    ounces => OUNCE,
+    stone => STONE,
+    short_tons => SHORT_TON,
+    long_tons => LONG_TON,
+    pounds_force => POUND_FORCE,
+    psi => PSI,
+    foot_pounds => FOOT_POUND,
+    btus => BTU,
+    horsepower => HORSEPOWER,
+    miles_per_hour => MILE_PER_HOUR,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{base::BaseValue, derived::DerivedValue, scaled::ScaledValue};
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() <= 1e-12 * expected.abs(),
+            "{expected} != {actual}"
+        );
+    }
+
+    #[test]
+    fn test_exact_definitions() {
+        let multiple = |unit: &ScaledUnit, count: i128, of: &ScaledUnit| {
+            assert_eq!(
+                Some(unit.factor()),
+                of.factor().checked_mul(Factor::integer(count)),
+                "{unit}"
+            );
+        };
+        multiple(&FOOT, 12, &INCH);
+        multiple(&YARD, 3, &FOOT);
+        multiple(&MILE, 5280, &FOOT);
+        let cubic_inch = INCH.factor().checked_pow(3).unwrap();
+        assert_eq!(
+            cubic_inch.checked_mul(Factor::integer(231)),
+            Some(GALLON.factor())
+        );
+        multiple(&GALLON, 8, &PINT);
+        multiple(&PINT, 16, &FLUID_OUNCE);
+        multiple(&IMPERIAL_GALLON, 8, &IMPERIAL_PINT);
+        multiple(&IMPERIAL_PINT, 20, &IMPERIAL_FLUID_OUNCE);
+        multiple(&POUND, 16, &OUNCE);
+        multiple(&STONE, 14, &POUND);
+        multiple(&SHORT_TON, 2000, &POUND);
+        multiple(&LONG_TON, 2240, &POUND);
+        multiple(&HORSEPOWER, 550, &FOOT_POUND);
+
+        let standard_gravity = Factor::decimal(980665, -5);
+        assert_eq!(
+            POUND.factor().checked_mul(standard_gravity),
+            Some(POUND_FORCE.factor())
+        );
+        assert_eq!(
+            FOOT.factor().checked_mul(POUND_FORCE.factor()),
+            Some(FOOT_POUND.factor())
+        );
+        let square_inch = INCH.factor().checked_pow(2).unwrap();
+        assert_eq!(
+            square_inch.checked_mul(PSI.factor()),
+            Some(POUND_FORCE.factor())
+        );
+        let hour = scaled::HOUR.factor().checked_recip().unwrap();
+        assert_eq!(
+            MILE.factor().checked_mul(hour),
+            Some(MILE_PER_HOUR.factor())
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_close(0.3048, *DerivedValue::feet(1.0).number());
+        assert_close(4.4482216152605, *BaseValue::pounds_force(1.0).number());
+        assert_eq!(base::NEWTON, BaseValue::pounds_force(1.0).unit);
+        assert_close(1055.05585262, *DerivedValue::btus(1.0).number());
+        assert_close(745.6998715822702, *DerivedValue::horsepower(1.0).number());
+
+        let distance = DerivedValue::meters(1609.344f64);
+        assert_close(1.0, distance.in_unit(&MILE).unwrap());
+        assert_close(1760.0, distance.in_unit(&YARD).unwrap());
+        assert_eq!("1 mi", distance.to_unit(&MILE).unwrap().to_string());
+
+        let litres = DerivedValue::gallons(1.0).in_unit(&scaled::LITRE).unwrap();
+        assert_close(3.785411784, litres);
+        let us = DerivedValue::imperial_gallons(1.0)
+            .in_unit(&GALLON)
+            .unwrap();
+        assert_close(1.200949925504855, us);
+
+        assert!(DerivedValue::feet(1.0).in_unit(&POUND).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(FOOT, "ft".parse().unwrap());
+        assert_eq!(IMPERIAL_FLUID_OUNCE, "imp fl oz".parse().unwrap());
+        assert_eq!(DEGREE_FAHRENHEIT, "°F".parse().unwrap());
+
+        let value: ScaledValue<f64> = "14.7 psi".parse().unwrap();
+        assert_eq!(PSI, *value.unit());
+        assert_eq!("14.7 psi", value.to_string());
+        assert_close(101352.93220957491, *value.to_derived().number());
+
+        let value: ScaledValue<f64> = "3 ft⋅lbf".parse().unwrap();
+        assert_eq!(DerivedValue::foot_pounds(3.0), value.to_derived());
+    }
+}
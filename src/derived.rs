@@ -1,54 +1,142 @@
-use core::fmt;
-use std::ops::{Add, Div, Mul, Sub};
+use core::{fmt, hash::Hash};
+use std::{
+    iter::{self, Product, Sum},
+    ops::{Add, Div, DivAssign, Mul, MulAssign, Neg, Sub},
+};
 
 use thiserror::Error;
 
 use crate::{
     base::{self, BaseUnit},
-    exponents::UnitExponent,
+    exponents::{UnitError, UnitExponent},
 };
 
-#[derive(Default, Clone, Copy)]
-pub struct DerivedUnit<ExponentType = i8> {
-    /// remaining base units
-    pub(crate) base: BaseUnit<ExponentType>,
-
-    /// hertz (Hz)
-    pub(crate) hertz: ExponentType,
-    /// newton (N)
-    pub(crate) newton: ExponentType,
-    /// pascal (Pa)
-    pub(crate) pascal: ExponentType,
-    /// joule (J)
-    pub(crate) joule: ExponentType,
-    /// watt (W)
-    pub(crate) watt: ExponentType,
-    /// coulomb (C)
-    pub(crate) coulomb: ExponentType,
-    /// volt (V)
-    pub(crate) volt: ExponentType,
-    /// farad (F)
-    pub(crate) farad: ExponentType,
-    /// ohm (Ω)
-    pub(crate) ohm: ExponentType,
-    /// siemens (S)
-    pub(crate) siemens: ExponentType,
-    /// weber (Wb)
-    pub(crate) weber: ExponentType,
-    /// tesla (T)
-    pub(crate) tesla: ExponentType,
-    /// henry (H)
-    pub(crate) henry: ExponentType,
-    /// lux (lx)
-    pub(crate) lux: ExponentType,
-    /// becquerel (Bq)
-    pub(crate) becquerel: ExponentType,
-    /// gray (Gy)
-    pub(crate) gray: ExponentType,
-    /// sievert (Sv)
-    pub(crate) sievert: ExponentType,
-    /// katal (kat)
-    pub(crate) katal: ExponentType,
+/// Defines `DerivedUnit`, `UNITLESS`, a constant for each named unit, and `NamedUnit`
+/// from one table so that a named unit can be added by adding a row.
+///
+/// Each row is the exponent's field, the `NamedUnit` variant, the constant, the symbol, the name,
+/// and what the unit is in base units (and angles, e.g. `lm` is `cd⋅sr`).
+macro_rules! named_units {
+    ($(
+        $(#[$attr:meta])*
+        $field:ident, $variant:ident, $constant:ident, $symbol:literal, $name:literal = $definition:expr;
+    )*) => {
+        #[derive(Default, Clone, Copy)]
+        pub struct DerivedUnit<ExponentType = i8> {
+            /// remaining base units
+            pub(crate) base: BaseUnit<ExponentType>,
+
+            $(
+                #[doc = concat!($name, " (", $symbol, ")")]
+                $(#[$attr])*
+                pub(crate) $field: ExponentType,
+            )*
+        }
+
+        pub const UNITLESS: DerivedUnit<i8> = DerivedUnit {
+            base: base::UNITLESS,
+            $($field: 0,)*
+        };
+
+        $(
+            #[doc = concat!($name, " (", $symbol, ")")]
+            pub const $constant: DerivedUnit = DerivedUnit {
+                $field: 1,
+                ..UNITLESS
+            };
+        )*
+
+        /// One of the SI coherent derived units with special names
+        /// (except the degree Celsius, see `scaled::DEGREE_CELSIUS`).
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum NamedUnit {
+            $($variant,)*
+        }
+
+        impl NamedUnit {
+            /// All of the named units in the order of the SI table.
+            pub const ALL: [NamedUnit; [$(NamedUnit::$variant),*].len()] = [$(NamedUnit::$variant),*];
+
+            /// The unit (e.g. `NamedUnit::Newton.unit()` is `NEWTON`).
+            pub const fn unit(self) -> DerivedUnit {
+                match self {
+                    $(NamedUnit::$variant => $constant,)*
+                }
+            }
+
+            /// The symbol (e.g. `"N"`).
+            pub const fn symbol(self) -> &'static str {
+                match self {
+                    $(NamedUnit::$variant => $symbol,)*
+                }
+            }
+
+            /// The full name (e.g. `"newton"`).
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(NamedUnit::$variant => $name,)*
+                }
+            }
+
+            /// The unit written with base units and angles instead
+            /// (e.g. `kg⋅m/s²` for the newton and `cd⋅sr` for the lumen).
+            pub const fn definition(self) -> DerivedUnit {
+                match self {
+                    $(NamedUnit::$variant => $definition,)*
+                }
+            }
+        }
+
+        impl<ExponentType: Copy> DerivedUnit<ExponentType> {
+            /// The exponent of each named unit in `NamedUnit::ALL` order.
+            pub(crate) fn named_exponents(&self) -> [ExponentType; NamedUnit::ALL.len()] {
+                [$(self.$field),*]
+            }
+
+            /// The inverse of `base` and `named_exponents`.
+            pub(crate) const fn from_parts(
+                base: BaseUnit<ExponentType>,
+                named: [ExponentType; NamedUnit::ALL.len()],
+            ) -> Self {
+                let [$($field),*] = named;
+                Self { base, $($field),* }
+            }
+        }
+    };
+}
+
+/// A named unit written as base units
+const fn of_base(base: BaseUnit) -> DerivedUnit {
+    DerivedUnit { base, ..UNITLESS }
+}
+
+named_units! {
+    /// which is dimensionless but tracked to distinguish angles
+    radian, Radian, RADIAN, "rad", "radian" = RADIAN;
+    /// which is dimensionless but tracked to distinguish solid angles
+    steradian, Steradian, STERADIAN, "sr", "steradian" = STERADIAN;
+    hertz, Hertz, HERTZ, "Hz", "hertz" = of_base(base::HERTZ);
+    newton, Newton, NEWTON, "N", "newton" = of_base(base::NEWTON);
+    pascal, Pascal, PASCAL, "Pa", "pascal" = of_base(base::PASCAL);
+    joule, Joule, JOULE, "J", "joule" = of_base(base::JOULE);
+    watt, Watt, WATT, "W", "watt" = of_base(base::WATT);
+    coulomb, Coulomb, COULOMB, "C", "coulomb" = of_base(base::COULOMB);
+    volt, Volt, VOLT, "V", "volt" = of_base(base::VOLT);
+    farad, Farad, FARAD, "F", "farad" = of_base(base::FARAD);
+    ohm, Ohm, OHM, "Ω", "ohm" = of_base(base::OHM);
+    siemens, Siemens, SIEMENS, "S", "siemens" = of_base(base::SIEMENS);
+    weber, Weber, WEBER, "Wb", "weber" = of_base(base::WEBER);
+    tesla, Tesla, TESLA, "T", "tesla" = of_base(base::TESLA);
+    henry, Henry, HENRY, "H", "henry" = of_base(base::HENRY);
+    lumen, Lumen, LUMEN, "lm", "lumen" = DerivedUnit {
+        steradian: 1,
+        ..of_base(base::CANDELA)
+    };
+    lux, Lux, LUX, "lx", "lux" = of_base(base::LUX);
+    becquerel, Becquerel, BECQUEREL, "Bq", "becquerel" = of_base(base::BECQUEREL);
+    gray, Gray, GRAY, "Gy", "gray" = of_base(base::GRAY);
+    sievert, Sievert, SIEVERT, "Sv", "sievert" = of_base(base::SIEVERT);
+    katal, Katal, KATAL, "kat", "katal" = of_base(base::KATAL);
 }
 
 impl<ExponentType> PartialEq for DerivedUnit<ExponentType>
@@ -62,151 +150,141 @@ where
 
 impl<ExponentType> Eq for DerivedUnit<ExponentType> where ExponentType: UnitExponent {}
 
+/// Hashes the unit in base units so that equal units
+/// (e.g. `N` and `kg⋅m/s²`) have equal hashes.
+impl<ExponentType> Hash for DerivedUnit<ExponentType>
+where
+    ExponentType: UnitExponent + Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.to_base().hash(state);
+    }
+}
+
 impl<ExponentType> DerivedUnit<ExponentType>
 where
     ExponentType: UnitExponent,
 {
     pub fn unitless() -> Self {
-        let zero = ExponentType::ZERO;
-        Self {
-            base: BaseUnit::unitless(),
-            hertz: zero,
-            newton: zero,
-            pascal: zero,
-            joule: zero,
-            watt: zero,
-            coulomb: zero,
-            volt: zero,
-            farad: zero,
-            ohm: zero,
-            siemens: zero,
-            weber: zero,
-            tesla: zero,
-            henry: zero,
-            lux: zero,
-            becquerel: zero,
-            gray: zero,
-            sievert: zero,
-            katal: zero,
+        Self::from_parts(
+            BaseUnit::unitless(),
+            [ExponentType::ZERO; NamedUnit::ALL.len()],
+        )
+    }
+
+    /// Combines the named exponents of two units pairwise.
+    fn zip_named(
+        self,
+        other: Self,
+        f: impl Fn(ExponentType, ExponentType) -> ExponentType,
+    ) -> [ExponentType; NamedUnit::ALL.len()] {
+        let other = other.named_exponents();
+        let mut named = self.named_exponents();
+        for (n, m) in named.iter_mut().zip(other) {
+            *n = f(*n, m);
         }
+        named
     }
 
-    pub fn multiply(self, other: Self) -> Self {
-        Self {
-            base: self.base.multiply(other.base),
-            hertz: self.hertz + other.hertz,
-            newton: self.newton + other.newton,
-            pascal: self.pascal + other.pascal,
-            joule: self.joule + other.joule,
-            watt: self.watt + other.watt,
-            coulomb: self.coulomb + other.coulomb,
-            volt: self.volt + other.volt,
-            farad: self.farad + other.farad,
-            ohm: self.ohm + other.ohm,
-            siemens: self.siemens + other.siemens,
-            weber: self.weber + other.weber,
-            tesla: self.tesla + other.tesla,
-            henry: self.henry + other.henry,
-            lux: self.lux + other.lux,
-            becquerel: self.becquerel + other.becquerel,
-            gray: self.gray + other.gray,
-            sievert: self.sievert + other.sievert,
-            katal: self.katal + other.katal,
+    /// Combines the named exponents of two units pairwise, failing if any of them overflow.
+    fn try_zip_named(
+        self,
+        other: Self,
+        f: impl Fn(ExponentType, ExponentType) -> Option<ExponentType>,
+    ) -> Result<[ExponentType; NamedUnit::ALL.len()], UnitError> {
+        let other = other.named_exponents();
+        let mut named = self.named_exponents();
+        for (n, m) in named.iter_mut().zip(other) {
+            *n = f(*n, m).ok_or(UnitError::Overflow)?;
         }
+        Ok(named)
     }
 
-    pub fn divide(self, other: Self) -> Self {
-        Self {
-            base: self.base.divide(other.base),
-            hertz: self.hertz - other.hertz,
-            newton: self.newton - other.newton,
-            pascal: self.pascal - other.pascal,
-            joule: self.joule - other.joule,
-            watt: self.watt - other.watt,
-            coulomb: self.coulomb - other.coulomb,
-            volt: self.volt - other.volt,
-            farad: self.farad - other.farad,
-            ohm: self.ohm - other.ohm,
-            siemens: self.siemens - other.siemens,
-            weber: self.weber - other.weber,
-            tesla: self.tesla - other.tesla,
-            henry: self.henry - other.henry,
-            lux: self.lux - other.lux,
-            becquerel: self.becquerel - other.becquerel,
-            gray: self.gray - other.gray,
-            sievert: self.sievert - other.sievert,
-            katal: self.katal - other.katal,
+    /// Applies a fallible function to each named exponent, stopping at the first error.
+    fn try_map_named(
+        self,
+        f: impl Fn(ExponentType) -> Result<ExponentType, UnitError>,
+    ) -> Result<[ExponentType; NamedUnit::ALL.len()], UnitError> {
+        let mut named = self.named_exponents();
+        for n in &mut named {
+            *n = f(*n)?;
         }
+        Ok(named)
+    }
+
+    pub fn multiply(self, other: Self) -> Self {
+        let named = self.zip_named(other, |a, b| a + b);
+        Self::from_parts(self.base.multiply(other.base), named)
+    }
+
+    pub fn divide(self, other: Self) -> Self {
+        let named = self.zip_named(other, |a, b| a - b);
+        Self::from_parts(self.base.divide(other.base), named)
     }
 
     pub fn pow(self, power: i8) -> Self {
-        Self {
-            base: self.base.pow(power),
-            hertz: self.hertz * power,
-            newton: self.newton * power,
-            pascal: self.pascal * power,
-            joule: self.joule * power,
-            watt: self.watt * power,
-            coulomb: self.coulomb * power,
-            volt: self.volt * power,
-            farad: self.farad * power,
-            ohm: self.ohm * power,
-            siemens: self.siemens * power,
-            weber: self.weber * power,
-            tesla: self.tesla * power,
-            henry: self.henry * power,
-            lux: self.lux * power,
-            becquerel: self.becquerel * power,
-            gray: self.gray * power,
-            sievert: self.sievert * power,
-            katal: self.katal * power,
-        }
+        let named = self.named_exponents().map(|n| n * power);
+        Self::from_parts(self.base.pow(power), named)
     }
 
     pub fn root(self, root: i8) -> Self {
-        Self {
-            base: self.base.root(root),
-            hertz: self.hertz.strict_div(root),
-            newton: self.newton.strict_div(root),
-            pascal: self.pascal.strict_div(root),
-            joule: self.joule.strict_div(root),
-            watt: self.watt.strict_div(root),
-            coulomb: self.coulomb.strict_div(root),
-            volt: self.volt.strict_div(root),
-            farad: self.farad.strict_div(root),
-            ohm: self.ohm.strict_div(root),
-            siemens: self.siemens.strict_div(root),
-            weber: self.weber.strict_div(root),
-            tesla: self.tesla.strict_div(root),
-            henry: self.henry.strict_div(root),
-            lux: self.lux.strict_div(root),
-            becquerel: self.becquerel.strict_div(root),
-            gray: self.gray.strict_div(root),
-            sievert: self.sievert.strict_div(root),
-            katal: self.katal.strict_div(root),
-        }
+        let named = self.named_exponents().map(|n| n.strict_div(root));
+        Self::from_parts(self.base.root(root), named)
+    }
+
+    /// The non-panicking version of `multiply`.
+    pub fn checked_multiply(self, other: Self) -> Result<Self, UnitError> {
+        self.checked_multiply_exponents(other)?.in_range()
+    }
+
+    /// The non-panicking version of `divide`.
+    pub fn checked_divide(self, other: Self) -> Result<Self, UnitError> {
+        self.checked_divide_exponents(other)?.in_range()
+    }
+
+    /// Adds the exponents of the units without checking that the result's base exponents
+    /// are in range, which identities (e.g. `N⋅s²/(kg⋅m)`) never change.
+    pub(crate) fn checked_multiply_exponents(self, other: Self) -> Result<Self, UnitError> {
+        let base = self.base.checked_multiply(other.base)?;
+        let named = self.try_zip_named(other, ExponentType::checked_add)?;
+        Ok(Self::from_parts(base, named))
+    }
+
+    /// Subtracts the exponents of the units, see `checked_multiply_exponents`.
+    pub(crate) fn checked_divide_exponents(self, other: Self) -> Result<Self, UnitError> {
+        let base = self.base.checked_divide(other.base)?;
+        let named = self.try_zip_named(other, ExponentType::checked_sub)?;
+        Ok(Self::from_parts(base, named))
+    }
+
+    /// The non-panicking version of `pow`.
+    pub fn checked_pow(self, power: i8) -> Result<Self, UnitError> {
+        let base = self.base.checked_pow(power)?;
+        let named = self.try_map_named(|n| n.checked_mul(power).ok_or(UnitError::Overflow))?;
+        Self::from_parts(base, named).in_range()
+    }
+
+    /// The non-panicking version of `root`.
+    pub fn checked_root(self, root: i8) -> Result<Self, UnitError> {
+        let base = self.base.checked_root(root)?;
+        let named = self.try_map_named(|n| n.exact_div(root))?;
+        Self::from_parts(base, named).in_range()
+    }
+
+    /// Fails if the unit's base exponents are out of range,
+    /// since comparing, hashing, and adding units converts them to base units.
+    fn in_range(self) -> Result<Self, UnitError> {
+        self.checked_to_base()?;
+        Ok(self)
     }
 
     pub(crate) fn magnitude(self) -> u16 {
         self.base.magnitude()
-            + self.hertz.magnitude()
-            + self.newton.magnitude()
-            + self.pascal.magnitude()
-            + self.joule.magnitude()
-            + self.watt.magnitude()
-            + self.coulomb.magnitude()
-            + self.volt.magnitude()
-            + self.farad.magnitude()
-            + self.ohm.magnitude()
-            + self.siemens.magnitude()
-            + self.weber.magnitude()
-            + self.tesla.magnitude()
-            + self.henry.magnitude()
-            + self.lux.magnitude()
-            + self.becquerel.magnitude()
-            + self.gray.magnitude()
-            + self.sievert.magnitude()
-            + self.katal.magnitude()
+            + self
+                .named_exponents()
+                .into_iter()
+                .map(UnitExponent::magnitude)
+                .sum::<u16>()
     }
 }
 
@@ -238,9 +316,42 @@ pub struct DerivedValue<Number, ExponentType = i8> {
     pub(crate) number: Number,
 }
 
-#[derive(Error)]
+impl<Number, ExponentType> DerivedValue<Number, ExponentType> {
+    pub const fn new(number: Number, unit: DerivedUnit<ExponentType>) -> Self {
+        Self { unit, number }
+    }
+
+    pub const fn number(&self) -> &Number {
+        &self.number
+    }
+
+    pub const fn unit(&self) -> &DerivedUnit<ExponentType> {
+        &self.unit
+    }
+
+    /// Splits the value into its number and unit.
+    pub fn into_parts(self) -> (Number, DerivedUnit<ExponentType>) {
+        (self.number, self.unit)
+    }
+
+    /// Transforms the number while keeping the unit (e.g. to change its type).
+    pub fn map_number<Output>(
+        self,
+        f: impl FnOnce(Number) -> Output,
+    ) -> DerivedValue<Output, ExponentType> {
+        DerivedValue {
+            unit: self.unit,
+            number: f(self.number),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
 #[error("Unit '{lhs}' didn't match '{rhs}'")]
-pub struct UnitMismatch<ExponentType> {
+pub struct UnitMismatch<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
     pub lhs: DerivedUnit<ExponentType>,
     pub rhs: DerivedUnit<ExponentType>,
 }
@@ -262,25 +373,14 @@ where
 {
 }
 
-impl<Number, ExponentType> PartialOrd for DerivedValue<Number, ExponentType>
+impl<Number, ExponentType> Hash for DerivedValue<Number, ExponentType>
 where
-    ExponentType: UnitExponent,
-    Number: PartialOrd,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        assert_eq!(self.unit, other.unit);
-        self.number.partial_cmp(&other.number)
-    }
-}
-
-impl<Number, ExponentType> Ord for DerivedValue<Number, ExponentType>
-where
-    ExponentType: UnitExponent,
-    Number: Ord,
+    ExponentType: UnitExponent + Hash,
+    Number: Hash,
 {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        assert_eq!(self.unit, other.unit);
-        self.number.cmp(&other.number)
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.unit.hash(state);
+        self.number.hash(state);
     }
 }
 
@@ -297,6 +397,20 @@ where
     }
 }
 
+impl<ExponentType, Number> Neg for DerivedValue<Number, ExponentType>
+where
+    Number: Neg<Output = Number>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            unit: self.unit,
+            number: -self.number,
+        }
+    }
+}
+
 impl<ExponentType, Number> Add for DerivedValue<Number, ExponentType>
 where
     ExponentType: UnitExponent,
@@ -371,27 +485,113 @@ where
     }
 }
 
-pub const UNITLESS: DerivedUnit<i8> = DerivedUnit {
-    base: base::UNITLESS,
-    hertz: 0,
-    newton: 0,
-    pascal: 0,
-    joule: 0,
-    watt: 0,
-    coulomb: 0,
-    volt: 0,
-    farad: 0,
-    ohm: 0,
-    siemens: 0,
-    weber: 0,
-    tesla: 0,
-    henry: 0,
-    lux: 0,
-    becquerel: 0,
-    gray: 0,
-    sievert: 0,
-    katal: 0,
-};
+impl<ExponentType, Number> MulAssign for DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: MulAssign,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        self.unit = self.unit * rhs.unit;
+        self.number *= rhs.number;
+    }
+}
+
+impl<ExponentType, Number> DivAssign for DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: DivAssign,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        self.unit = self.unit / rhs.unit;
+        self.number /= rhs.number;
+    }
+}
+
+impl<ExponentType, Number> DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// Adds up the values in `unit`, failing on the first value whose unit doesn't match.
+    /// An empty iterator sums to zero in `unit`.
+    pub fn try_sum<I>(
+        unit: DerivedUnit<ExponentType>,
+        values: I,
+    ) -> Result<Self, UnitMismatch<ExponentType>>
+    where
+        I: IntoIterator<Item = Self>,
+        Number: Add<Output = Number> + Default,
+    {
+        let zero = Self {
+            unit,
+            number: Number::default(),
+        };
+        values.into_iter().try_fold(zero, |sum, value| sum + value)
+    }
+
+    /// Multiplies the values, failing if a unit exponent leaves its range.
+    /// An empty iterator multiplies to a unitless one.
+    pub fn try_product<I>(values: I) -> Result<Self, UnitError>
+    where
+        I: IntoIterator<Item = Self>,
+        Number: Mul<Output = Number> + Product,
+    {
+        let one = Self {
+            unit: DerivedUnit::unitless(),
+            number: iter::empty::<Number>().product(),
+        };
+        values
+            .into_iter()
+            .try_fold(one, |product, value| product.checked_multiply(value))
+    }
+}
+
+/// Sums values into a `Result` (e.g. `values.into_iter().sum::<Result<_, _>>()`)
+/// in the unit of the first value, failing on the first value whose unit doesn't match.
+/// An empty iterator has no unit to sum in and gives a unitless zero,
+/// so use `try_sum` with the expected unit when the iterator may be empty.
+impl<ExponentType, Number> Sum<DerivedValue<Number, ExponentType>>
+    for Result<DerivedValue<Number, ExponentType>, UnitMismatch<ExponentType>>
+where
+    ExponentType: UnitExponent,
+    Number: Add<Output = Number> + Default,
+{
+    fn sum<I: Iterator<Item = DerivedValue<Number, ExponentType>>>(mut iter: I) -> Self {
+        let Some(first) = iter.next() else {
+            return Ok(DerivedValue {
+                unit: DerivedUnit::unitless(),
+                number: Number::default(),
+            });
+        };
+        iter.try_fold(first, |sum, value| sum + value)
+    }
+}
+
+impl<Number, ExponentType> DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// The non-panicking version of `*`.
+    pub fn checked_multiply(self, rhs: Self) -> Result<Self, UnitError>
+    where
+        Number: Mul<Output = Number>,
+    {
+        Ok(Self {
+            unit: self.unit.checked_multiply(rhs.unit)?,
+            number: self.number * rhs.number,
+        })
+    }
+
+    /// The non-panicking version of `/`.
+    pub fn checked_divide(self, rhs: Self) -> Result<Self, UnitError>
+    where
+        Number: Div<Output = Number>,
+    {
+        Ok(Self {
+            unit: self.unit.checked_divide(rhs.unit)?,
+            number: self.number / rhs.number,
+        })
+    }
+}
 
 pub const METER: DerivedUnit = DerivedUnit {
     base: base::METER,
@@ -433,112 +633,6 @@ pub const METER_SQ: DerivedUnit = DerivedUnit {
     ..UNITLESS
 };
 
-/// hertz (Hz)
-pub const HERTZ: DerivedUnit = DerivedUnit {
-    hertz: 1,
-    ..UNITLESS
-};
-
-/// newton (N)
-pub const NEWTON: DerivedUnit = DerivedUnit {
-    newton: 1,
-    ..UNITLESS
-};
-
-/// pascal (Pa)
-pub const PASCAL: DerivedUnit = DerivedUnit {
-    pascal: 1,
-    ..UNITLESS
-};
-
-/// joule (J)
-pub const JOULE: DerivedUnit = DerivedUnit {
-    joule: 1,
-    ..UNITLESS
-};
-
-/// watt (W)
-pub const WATT: DerivedUnit = DerivedUnit {
-    watt: 1,
-    ..UNITLESS
-};
-
-/// coulomb (C)
-pub const COULOMB: DerivedUnit = DerivedUnit {
-    coulomb: 1,
-    ..UNITLESS
-};
-
-/// volt (V)
-pub const VOLT: DerivedUnit = DerivedUnit {
-    volt: 1,
-    ..UNITLESS
-};
-
-/// farad (F)
-pub const FARAD: DerivedUnit = DerivedUnit {
-    farad: 1,
-    ..UNITLESS
-};
-
-/// ohm (Ω)
-pub const OHM: DerivedUnit = DerivedUnit { ohm: 1, ..UNITLESS };
-
-/// siemens (S)
-pub const SIEMENS: DerivedUnit = DerivedUnit {
-    siemens: 1,
-    ..UNITLESS
-};
-
-/// weber (Wb)
-pub const WEBER: DerivedUnit = DerivedUnit {
-    weber: 1,
-    ..UNITLESS
-};
-
-/// tesla (T)
-pub const TESLA: DerivedUnit = DerivedUnit {
-    tesla: 1,
-    ..UNITLESS
-};
-
-/// henry (H)
-pub const HENRY: DerivedUnit = DerivedUnit {
-    henry: 1,
-    ..UNITLESS
-};
-
-// TODO: celsius (C)
-
-// TODO: lumen (lm)
-
-/// lux (lx)
-pub const LUX: DerivedUnit = DerivedUnit { lux: 1, ..UNITLESS };
-
-/// becquerel (Bq)
-pub const BECQUEREL: DerivedUnit = DerivedUnit {
-    becquerel: 1,
-    ..UNITLESS
-};
-
-/// gray (Gy)
-pub const GRAY: DerivedUnit = DerivedUnit {
-    gray: 1,
-    ..UNITLESS
-};
-
-/// sievert (Sv)
-pub const SIEVERT: DerivedUnit = DerivedUnit {
-    sievert: 1,
-    ..UNITLESS
-};
-
-/// katal (kat)
-pub const KATAL: DerivedUnit = DerivedUnit {
-    katal: 1,
-    ..UNITLESS
-};
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -585,7 +679,9 @@ mod tests {
         assert_eq!(HENRY, OHM * SECOND);
         assert_eq!(HENRY, WEBER / AMPERE);
 
+        assert_eq!(LUMEN, CANDELA * STERADIAN);
         assert_eq!(LUX, CANDELA / (METER * METER));
+        assert_eq!(LUX, LUMEN / (METER * METER));
 
         assert_eq!(BECQUEREL, UNITLESS / SECOND);
 
@@ -594,5 +690,167 @@ mod tests {
         assert_eq!(SIEVERT, JOULE / KILOGRAM);
 
         assert_eq!(KATAL, MOLE / SECOND);
+
+        for named in NamedUnit::ALL {
+            assert_eq!(named.unit(), named.definition(), "{}", named.name());
+            let unit = named.unit().pow(3);
+            assert_eq!(
+                unit.to_string(),
+                DerivedUnit::from_parts(unit.base, unit.named_exponents()).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(Ok(JOULE), NEWTON.checked_multiply(METER));
+        assert_eq!(Ok(WATT), JOULE.checked_divide(SECOND));
+        assert_eq!(Ok(NEWTON.pow(3)), NEWTON.checked_pow(3));
+        assert_eq!(Ok(VOLT), VOLT.pow(4).checked_root(4));
+
+        let big = VOLT.pow(100);
+        assert_eq!(Err(UnitError::Overflow), big.checked_multiply(big));
+        assert_eq!(Err(UnitError::Overflow), big.checked_pow(-2));
+        assert_eq!(
+            Err(UnitError::InexactRoot { root: 2 }),
+            VOLT.checked_root(2)
+        );
+        assert_eq!(Err(UnitError::DivisionByZero), VOLT.checked_root(0));
+
+        // The named exponents fit but the base exponents don't
+        let big = NEWTON.pow(100);
+        assert_eq!(Err(UnitError::Overflow), big.checked_to_base());
+        assert_eq!(
+            Err(UnitError::Overflow),
+            big.checked_multiply(NEWTON.pow(20))
+        );
+        assert_eq!(Err(UnitError::Overflow), NEWTON.pow(60).checked_pow(2));
+        assert_eq!(
+            Err(UnitError::Overflow),
+            big.checked_divide(NEWTON.pow(-20))
+        );
+        assert_eq!(Ok(NEWTON.pow(60)), NEWTON.pow(120).checked_root(2));
+        assert_eq!(
+            Ok(NEWTON.pow(60).to_base()),
+            NEWTON.pow(60).checked_to_base()
+        );
+
+        let energy = DerivedValue {
+            unit: JOULE,
+            number: 6,
+        };
+        let time = DerivedValue {
+            unit: SECOND,
+            number: 2,
+        };
+        assert_eq!(
+            Ok(DerivedValue {
+                unit: WATT,
+                number: 3
+            }),
+            energy.checked_divide(time)
+        );
+        let big_value = DerivedValue {
+            unit: big,
+            number: 1,
+        };
+        assert_eq!(
+            Err(UnitError::Overflow),
+            big_value.checked_multiply(big_value)
+        );
+    }
+
+    #[test]
+    fn test_parts() {
+        let power = DerivedValue::new(2.5f32, WATT);
+        assert_eq!(2.5, *power.number());
+        assert_eq!(WATT, *power.unit());
+        assert_eq!(DerivedValue::new(5.0, WATT), power.map_number(|n| n * 2.0));
+        let (number, unit) = power.into_parts();
+        assert_eq!((2.5, WATT), (number, unit));
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::{collections::HashMap, hash::BuildHasher};
+
+        let hasher = std::hash::RandomState::new();
+        let newton_as_base = KILOGRAM * METER / (SECOND * SECOND);
+        assert_eq!(hasher.hash_one(NEWTON), hasher.hash_one(newton_as_base));
+        assert_eq!(
+            hasher.hash_one(DerivedValue::new(3, JOULE)),
+            hasher.hash_one(DerivedValue::new(3, NEWTON * METER))
+        );
+        assert_eq!(
+            hasher.hash_one(DerivedValue::new(3, JOULE)),
+            hasher.hash_one(base::BaseValue::new(3, base::JOULE))
+        );
+
+        let mut counts = HashMap::new();
+        for unit in [NEWTON, JOULE, newton_as_base, JOULE / METER] {
+            *counts.entry(unit).or_insert(0) += 1;
+        }
+        assert_eq!(2, counts.len());
+        assert_eq!(Some(&3), counts.get(&NEWTON));
+    }
+
+    #[test]
+    fn test_reductions() {
+        let energies = vec![
+            DerivedValue::joules(1.5),
+            DerivedValue::new(2.0, NEWTON * METER),
+            DerivedValue::joules(0.5),
+        ];
+        assert_eq!(
+            Ok(DerivedValue::joules(4.0)),
+            DerivedValue::try_sum(JOULE, energies.clone())
+        );
+        assert_eq!(
+            Ok(DerivedValue::joules(4.0)),
+            energies.into_iter().sum::<Result<_, _>>()
+        );
+        assert_eq!(
+            Ok(DerivedValue::new(0, JOULE)),
+            DerivedValue::try_sum(JOULE, Vec::<DerivedValue<i32>>::new())
+        );
+        assert_eq!(
+            Ok(DerivedValue::new(0, UNITLESS)),
+            Vec::<DerivedValue<i32>>::new().into_iter().sum()
+        );
+
+        let error = DerivedValue::try_sum(
+            JOULE,
+            [
+                DerivedValue::joules(1),
+                DerivedValue::seconds(2),
+                DerivedValue::meters(3),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!((JOULE, SECOND), (error.lhs, error.rhs));
+
+        let sides = [DerivedValue::meters(2), DerivedValue::meters(3)];
+        assert_eq!(
+            Ok(DerivedValue::new(6, METER_SQ)),
+            DerivedValue::try_product(sides)
+        );
+        assert_eq!(
+            Ok(DerivedValue::new(1, UNITLESS)),
+            DerivedValue::try_product(Vec::<DerivedValue<i32>>::new())
+        );
+        let big = DerivedValue::new(1, METER.pow(100));
+        assert_eq!(
+            Err(UnitError::Overflow),
+            DerivedValue::try_product([big, big])
+        );
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut value = DerivedValue::newton(6.0);
+        value *= DerivedValue::meters(2.0);
+        assert_eq!(DerivedValue::joules(12.0), value);
+        value /= DerivedValue::seconds(4.0);
+        assert_eq!(DerivedValue::new(3.0, WATT), value);
     }
 }
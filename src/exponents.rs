@@ -3,10 +3,11 @@
 use core::fmt;
 use std::{
     fmt::Debug,
-    i8,
     ops::{Add, Div, Mul, Neg, Sub},
 };
 
+use thiserror::Error;
+
 /// A numeric value that can be used to represent an exponent of a unit
 /// e.g. the 2 in meters squared (m^2).
 ///
@@ -27,6 +28,7 @@ pub trait UnitExponent:
     + Debug
 {
     const ZERO: Self;
+    /// An exponent of one (e.g. `m`), which is four quarters for `FractionalExponent`.
     const ONE: Self;
 
     /// Takes an integer in the range (-32, 31) inclusive-inclusive
@@ -35,11 +37,37 @@ pub trait UnitExponent:
 
     fn strict_div(self, denominator: i8) -> Self;
 
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: i8) -> Option<Self>;
+
+    /// The non-panicking version of `strict_div`.
+    fn exact_div(self, denominator: i8) -> Result<Self, UnitError>;
+
     fn to_parts(self) -> ExponentParts;
 
+    /// The inverse of `to_parts` which returns `None`
+    /// if the value can't be represented by this type.
+    fn from_parts(parts: ExponentParts) -> Option<Self>;
+
     fn magnitude(self) -> u16;
 }
 
+/// An error from unit arithmetic that would otherwise panic
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitError {
+    #[error("unit exponent is out of range")]
+    Overflow,
+    #[error("root {root} of the unit leaves a remainder")]
+    InexactRoot { root: i8 },
+    #[error("can't take the zeroth root of a unit")]
+    DivisionByZero,
+    #[error("number can't be represented after the operation")]
+    UnrepresentableNumber,
+}
+
 pub struct ExponentParts {
     pub sign_positive: bool,
     pub whole_part: u8,
@@ -62,6 +90,29 @@ impl UnitExponent for i8 {
         self / denominator
     }
 
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i8::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i8::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: i8) -> Option<Self> {
+        i8::checked_mul(self, rhs)
+    }
+
+    fn exact_div(self, denominator: i8) -> Result<Self, UnitError> {
+        if denominator == 0 {
+            return Err(UnitError::DivisionByZero);
+        }
+        match self.checked_rem(denominator) {
+            Some(0) => Ok(self / denominator),
+            Some(_) => Err(UnitError::InexactRoot { root: denominator }),
+            None => Err(UnitError::Overflow),
+        }
+    }
+
     fn to_parts(self) -> ExponentParts {
         ExponentParts {
             sign_positive: self >= 0,
@@ -70,6 +121,14 @@ impl UnitExponent for i8 {
         }
     }
 
+    fn from_parts(parts: ExponentParts) -> Option<Self> {
+        if parts.percent_part != 0 {
+            return None;
+        }
+        let whole = parts.whole_part as i16;
+        i8::try_from(if parts.sign_positive { whole } else { -whole }).ok()
+    }
+
     fn magnitude(self) -> u16 {
         self.unsigned_abs() as u16
     }
@@ -100,7 +159,7 @@ impl UnitExponent for i8 {
 /// ## Supported Exponent Arithmetic
 ///
 /// * Addition and subtraction between unit exponents is fully supported and overflows
-/// the same way as integers in Rust do by default.
+///   the same way as integers in Rust do by default.
 /// * Unit exponents may be multiplied by unsigned integers and overflow normally.
 /// * Unit exponents may be divided by unsigned integers, but division by zero panics and division must be exact
 ///
@@ -155,6 +214,16 @@ impl FractionalExponent {
         FractionalExponent(int * 4)
     }
 
+    /// The exponent as a number of quarters (e.g. 6 for 1 1/2)
+    pub const fn quarters(self) -> i8 {
+        self.0
+    }
+
+    /// Takes a number of quarters (e.g. 6 for 1 1/2)
+    pub const fn from_quarters(quarters: i8) -> Self {
+        Self(quarters)
+    }
+
     pub const fn const_add(self, rhs: Self) -> Self {
         FractionalExponent(self.0 + rhs.0)
     }
@@ -187,7 +256,7 @@ impl FractionalExponent {
 
 impl UnitExponent for FractionalExponent {
     const ZERO: Self = Self(0);
-    const ONE: Self = Self(1);
+    const ONE: Self = Self(4);
 
     fn from_int(int: i8) -> Self {
         Self::from_int(int)
@@ -197,6 +266,22 @@ impl UnitExponent for FractionalExponent {
         self.const_div(denominator)
     }
 
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    fn checked_mul(self, rhs: i8) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    fn exact_div(self, denominator: i8) -> Result<Self, UnitError> {
+        <i8 as UnitExponent>::exact_div(self.0, denominator).map(Self)
+    }
+
     fn to_parts(self) -> ExponentParts {
         let abs = self.0.unsigned_abs();
         ExponentParts {
@@ -206,6 +291,19 @@ impl UnitExponent for FractionalExponent {
         }
     }
 
+    fn from_parts(parts: ExponentParts) -> Option<Self> {
+        if !parts.percent_part.is_multiple_of(25) || parts.percent_part >= 100 {
+            return None;
+        }
+        let magnitude = parts.whole_part as i16 * 4 + (parts.percent_part / 25) as i16;
+        let quarters = if parts.sign_positive {
+            magnitude
+        } else {
+            -magnitude
+        };
+        i8::try_from(quarters).ok().map(Self)
+    }
+
     fn magnitude(self) -> u16 {
         self.magnitude()
     }
@@ -380,6 +478,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::erasing_op)]
     fn multiplication_identities() {
         for i in ALL_I8_VALUES {
             let value = FractionalExponent(i);
@@ -404,4 +503,27 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn checked_arithmetic() {
+        assert_eq!(Some(ue(3)), UnitExponent::checked_add(ue(1), ue(2)));
+        assert_eq!(None, UnitExponent::checked_add(ue(31), ue(1)));
+        assert_eq!(None, UnitExponent::checked_sub(ue(-32), ue(1)));
+        assert_eq!(None, UnitExponent::checked_mul(ue(16), 2));
+        assert_eq!(None, UnitExponent::checked_mul(i8::MAX, 2));
+
+        assert_eq!(Ok(ue(1) / 4), ue(1).exact_div(4));
+        assert_eq!(Err(UnitError::InexactRoot { root: 8 }), ue(1).exact_div(8));
+        assert_eq!(Err(UnitError::InexactRoot { root: 2 }), 3i8.exact_div(2));
+        assert_eq!(Err(UnitError::DivisionByZero), 2i8.exact_div(0));
+        assert_eq!(Err(UnitError::Overflow), i8::MIN.exact_div(-1));
+    }
+
+    #[test]
+    fn one_is_a_whole_exponent() {
+        assert_eq!(FractionalExponent::from_int(1), FractionalExponent::ONE);
+        assert_eq!(ue(1), <FractionalExponent as UnitExponent>::ONE);
+        assert_ne!(ue(1) / 4, FractionalExponent::ONE);
+        assert_eq!(ue(3), FractionalExponent::ONE * 3);
+    }
 }
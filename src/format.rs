@@ -1,11 +1,15 @@
-use core::fmt;
+use core::{array, cmp::Ordering, fmt};
 
 use crate::{
     base,
-    derived::{self, DerivedUnit},
+    derived::{self, DerivedUnit, NamedUnit},
     exponents::{ExponentParts, UnitExponent},
+    prefix::{PowerOfTen, Prefix, PrefixedValue, leading_component, prefix_power_of_ten},
 };
 
+/// The number of base and named units written by `DerivedUnit`
+const COMPONENTS: usize = 7 + NamedUnit::ALL.len();
+
 impl<ExponentType> fmt::Debug for base::BaseUnit<ExponentType>
 where
     ExponentType: UnitExponent,
@@ -50,8 +54,17 @@ where
         if *self == Self::unitless() {
             return write!(f, "Unitless");
         }
+        fmt_components(f, &self.symbol_components(), None)
+    }
+}
 
-        let components = [
+impl<ExponentType> base::BaseUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// The exponent and symbol of each unit in display order
+    pub(crate) fn symbol_components(&self) -> [(ExponentType, &'static str); 7] {
+        [
             (self.kilogram, "kg"),
             (self.meter, "m"),
             (self.second, "s"),
@@ -59,57 +72,86 @@ where
             (self.ampere, "A"),
             (self.kelvin, "K"),
             (self.candela, "cd"),
-        ];
+        ]
+    }
+}
+
+/// Writes the components with positive exponents followed by
+/// a `/` and those with negative exponents (e.g. `kg⋅m/s²`).
+///
+/// If there is a prefix it is written before the first symbol and
+/// if that symbol is the kilogram the prefix replaces its "k".
+fn fmt_components<ExponentType>(
+    f: &mut std::fmt::Formatter<'_>,
+    components: &[(ExponentType, &str)],
+    prefix: Option<Prefix>,
+) -> std::fmt::Result
+where
+    ExponentType: UnitExponent,
+{
+    let mut prefix = prefix;
+    let mut write_symbol = |f: &mut std::fmt::Formatter<'_>, symbol: &str| match prefix.take() {
+        Some(prefix) if symbol == "kg" => write!(f, "{prefix}g"),
+        Some(prefix) => write!(f, "{prefix}{symbol}"),
+        None => write!(f, "{symbol}"),
+    };
+
+    let mut is_first = true;
+    for (n, symbol) in components.iter() {
+        let n = *n;
+        if n > ExponentType::ZERO {
+            if !is_first {
+                write!(f, "⋅")?;
+            }
+            write_symbol(f, symbol)?;
+            write!(f, "{}", n.to_parts())?;
+            is_first = false;
+        }
+    }
+
+    let negatives = components
+        .iter()
+        .filter(|(n, _)| *n < ExponentType::ZERO)
+        .count();
+    if negatives != 0 {
+        write!(f, "/")?;
+
+        if negatives > 1 {
+            write!(f, "(")?;
+        }
 
         let mut is_first = true;
         for (n, symbol) in components.iter() {
-            let n = *n;
-            if n > ExponentType::ZERO {
+            if *n < ExponentType::ZERO {
                 if !is_first {
                     write!(f, "⋅")?;
                 }
-                write!(f, "{symbol}{}", n.to_parts())?;
+                write_symbol(f, symbol)?;
+                // Flipping the sign of the parts rather than the exponent
+                // since the smallest exponent (e.g. -128) can't be negated
+                let parts = ExponentParts {
+                    sign_positive: true,
+                    ..n.to_parts()
+                };
+                write!(f, "{parts}")?;
                 is_first = false;
             }
         }
 
-        let negatives = components
-            .iter()
-            .filter(|(n, _)| *n < ExponentType::ZERO)
-            .count();
-        if negatives != 0 {
-            write!(f, "/")?;
-
-            if negatives > 1 {
-                write!(f, "(")?;
-            }
-
-            let mut is_first = true;
-            for (n, symbol) in components.iter() {
-                let n = -*n;
-                if n > ExponentType::ZERO {
-                    if !is_first {
-                        write!(f, "⋅")?;
-                    }
-                    write!(f, "{symbol}{}", n.to_parts())?;
-                    is_first = false;
-                }
-            }
-
-            if negatives > 1 {
-                write!(f, ")")?;
-            }
+        if negatives > 1 {
+            write!(f, ")")?;
         }
-        Ok(())
     }
+    Ok(())
 }
 
-impl<Number> fmt::Display for base::BaseValue<Number>
+impl<Number, ExponentType> fmt::Display for base::BaseValue<Number, ExponentType>
 where
+    ExponentType: UnitExponent,
     Number: fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.unit == base::UNITLESS {
+        if self.unit == base::BaseUnit::unitless() {
             write!(f, "{}", self.number)
         } else {
             write!(f, "{} {}", self.number, self.unit)
@@ -122,11 +164,11 @@ where
     ExponentType: UnitExponent,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if *self == DerivedUnit::unitless() {
+        if self.is_written_unitless() {
             return write!(f, "DerivedUnit::UNITLESS");
         }
 
-        let components = [
+        let base = [
             (self.base.kilogram, "kilogram"),
             (self.base.meter, "meter"),
             (self.base.second, "second"),
@@ -134,25 +176,8 @@ where
             (self.base.ampere, "ampere"),
             (self.base.kelvin, "kelvin"),
             (self.base.candela, "candela"),
-            (self.hertz, "hertz"),
-            (self.newton, "newton"),
-            (self.pascal, "pascal"),
-            (self.joule, "joule"),
-            (self.watt, "watt"),
-            (self.coulomb, "coulomb"),
-            (self.volt, "volt"),
-            (self.farad, "farad"),
-            (self.ohm, "ohm"),
-            (self.siemens, "siemens"),
-            (self.weber, "weber"),
-            (self.tesla, "tesla"),
-            (self.henry, "henry"),
-            (self.lux, "lux"),
-            (self.becquerel, "becquerel"),
-            (self.gray, "gray"),
-            (self.sievert, "sievert"),
-            (self.katal, "katal"),
         ];
+        let components = self.components(base, NamedUnit::name);
 
         write!(f, "DerivedUnit(")?;
 
@@ -171,86 +196,56 @@ where
     }
 }
 
-impl fmt::Display for derived::DerivedUnit {
+impl<ExponentType> fmt::Display for derived::DerivedUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if *self == derived::UNITLESS {
+        if self.is_written_unitless() {
             return write!(f, "Unitless");
         }
+        fmt_components(f, &self.symbol_components(), None)
+    }
+}
 
-        let components = [
-            (self.base.kilogram, "kg"),
-            (self.base.meter, "m"),
-            (self.base.second, "s"),
-            (self.base.mole, "mol"),
-            (self.base.ampere, "A"),
-            (self.base.kelvin, "K"),
-            (self.base.candela, "cd"),
-            (self.hertz, "Hz"),
-            (self.newton, "N"),
-            (self.pascal, "Pa"),
-            (self.joule, "J"),
-            (self.watt, "W"),
-            (self.coulomb, "C"),
-            (self.volt, "V"),
-            (self.farad, "F"),
-            (self.ohm, "Ω"),
-            (self.siemens, "S"),
-            (self.weber, "Wb"),
-            (self.tesla, "T"),
-            (self.henry, "H"),
-            (self.lux, "lx"),
-            (self.becquerel, "Bq"),
-            (self.gray, "Gy"),
-            (self.sievert, "Sv"),
-            (self.katal, "kat"),
-        ];
-
-        let mut is_first = true;
-        for (n, symbol) in components.iter() {
-            let n = *n;
-            if n > 0 {
-                if !is_first {
-                    write!(f, "⋅")?;
-                }
-                write!(f, "{symbol}{}", n.to_parts())?;
-                is_first = false;
-            }
-        }
-
-        let negatives = components.iter().filter(|(n, _)| *n < 0).count();
-        if negatives != 0 {
-            write!(f, "/")?;
-
-            if negatives > 1 {
-                write!(f, "(")?;
-            }
+impl<ExponentType> derived::DerivedUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// Whether the unit is written without any symbols,
+    /// which isn't the case for angles even though they're dimensionless.
+    fn is_written_unitless(&self) -> bool {
+        *self == DerivedUnit::unitless()
+            && self.radian == ExponentType::ZERO
+            && self.steradian == ExponentType::ZERO
+    }
 
-            let mut is_first = true;
-            for (n, symbol) in components.iter() {
-                let n = -*n;
-                if n > 0 {
-                    if !is_first {
-                        write!(f, "⋅")?;
-                    }
-                    write!(f, "{symbol}{}", n.to_parts())?;
-                    is_first = false;
-                }
-            }
+    /// The exponent and symbol of each unit in display order
+    pub(crate) fn symbol_components(&self) -> [(ExponentType, &'static str); COMPONENTS] {
+        self.components(self.base.symbol_components(), NamedUnit::symbol)
+    }
 
-            if negatives > 1 {
-                write!(f, ")")?;
-            }
-        }
-        Ok(())
+    /// The base components followed by each named unit labelled by `label`
+    fn components(
+        &self,
+        base: [(ExponentType, &'static str); 7],
+        label: fn(NamedUnit) -> &'static str,
+    ) -> [(ExponentType, &'static str); COMPONENTS] {
+        let named = self.named_exponents();
+        array::from_fn(|i| match i.checked_sub(base.len()) {
+            None => base[i],
+            Some(i) => (named[i], label(NamedUnit::ALL[i])),
+        })
     }
 }
 
-impl<Number> fmt::Display for derived::DerivedValue<Number>
+impl<Number, ExponentType> fmt::Display for derived::DerivedValue<Number, ExponentType>
 where
+    ExponentType: UnitExponent,
     Number: fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.unit == derived::UNITLESS {
+        if self.unit.is_written_unitless() {
             write!(f, "{}", self.number)
         } else {
             write!(f, "{} {}", self.number, self.unit)
@@ -258,13 +253,130 @@ where
     }
 }
 
+impl<Number, ExponentType> base::BaseValue<Number, ExponentType> {
+    /// Displays the value with a prefix on the first symbol of its unit (e.g. `3 km`).
+    pub fn display_with_prefix(
+        &self,
+        prefix: Prefix,
+    ) -> PrefixedValue<'_, Number, base::BaseUnit<ExponentType>> {
+        PrefixedValue {
+            number: &self.number,
+            unit: &self.unit,
+            prefix,
+        }
+    }
+}
+
+impl<Number, ExponentType> derived::DerivedValue<Number, ExponentType> {
+    /// Displays the value with a prefix on the first symbol of its unit (e.g. `3 kN`).
+    pub fn display_with_prefix(
+        &self,
+        prefix: Prefix,
+    ) -> PrefixedValue<'_, Number, DerivedUnit<ExponentType>> {
+        PrefixedValue {
+            number: &self.number,
+            unit: &self.unit,
+            prefix,
+        }
+    }
+}
+
+impl<Number, ExponentType> fmt::Display for PrefixedValue<'_, Number, base::BaseUnit<ExponentType>>
+where
+    ExponentType: UnitExponent,
+    Number: fmt::Display + PowerOfTen + Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_prefixed(
+            f,
+            self.number,
+            self.unit,
+            &self.unit.symbol_components(),
+            self.prefix,
+        )
+    }
+}
+
+impl<Number, ExponentType> fmt::Display for PrefixedValue<'_, Number, DerivedUnit<ExponentType>>
+where
+    ExponentType: UnitExponent,
+    Number: fmt::Display + PowerOfTen + Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_prefixed(
+            f,
+            self.number,
+            self.unit,
+            &self.unit.symbol_components(),
+            self.prefix,
+        )
+    }
+}
+
+/// Prefixes are only written when the exponent of the leading symbol is an integer
+/// since a prefix raised to a fractional power isn't a power of ten (e.g. √km).
+fn fmt_prefixed<Number, Unit, ExponentType, const N: usize>(
+    f: &mut std::fmt::Formatter<'_>,
+    number: &Number,
+    unit: &Unit,
+    components: &[(ExponentType, &str); N],
+    prefix: Prefix,
+) -> std::fmt::Result
+where
+    ExponentType: UnitExponent,
+    Number: fmt::Display + PowerOfTen + Clone,
+    Unit: fmt::Display,
+{
+    // Only the signs are needed to find the leading symbol
+    let mut exponents = components.map(|(n, _)| match n.cmp(&ExponentType::ZERO) {
+        Ordering::Less => -1i8,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    });
+    let Some((leading, _)) = leading_component(&exponents) else {
+        return write!(f, "{number}");
+    };
+    let parts = components[leading].0.to_parts();
+    match i8::try_from(parts.whole_part) {
+        Ok(whole) if parts.percent_part == 0 => {
+            exponents[leading] = if parts.sign_positive { whole } else { -whole };
+        }
+        _ => return write!(f, "{number} {unit}"),
+    }
+    let power = prefix_power_of_ten(prefix, &exponents);
+    match number.clone().checked_mul_power_of_ten(-power) {
+        Some(scaled) => {
+            write!(f, "{scaled} ")?;
+            fmt_components(f, components, Some(prefix))
+        }
+        None => write!(f, "{number} {unit}"),
+    }
+}
+
+/// The symbol of a unit with a prefix on its first symbol (e.g. `kN`)
+pub(crate) fn prefixed_unit_symbol(unit: &DerivedUnit, prefix: Prefix) -> String {
+    struct Prefixed<'a>(&'a DerivedUnit, Prefix);
+
+    impl fmt::Display for Prefixed<'_> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fmt_components(f, &self.0.symbol_components(), Some(self.1))
+        }
+    }
+
+    if unit.is_written_unitless() {
+        prefix.symbol().to_string()
+    } else {
+        Prefixed(unit, prefix).to_string()
+    }
+}
+
 impl fmt::Display for ExponentParts {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if self.percent_part == 0 {
             if self.whole_part == 0 {
                 return write!(f, "⁰");
             }
-            if self.whole_part == 1 {
+            if self.whole_part == 1 && self.sign_positive {
                 return Ok(());
             }
         }
@@ -344,6 +456,9 @@ mod tests {
             kilogram: 1,
         };
         assert_eq!(String::from("kg⋅m/s²"), format!("{}", unit));
+        // The smallest exponent can't be negated
+        assert_eq!("/kg¹²⁸", base::KILOGRAM.pow(-128).to_string());
+        assert_eq!("/kg¹²⁸", derived::KILOGRAM.pow(-128).to_string());
     }
 
     #[test]
@@ -381,6 +496,11 @@ mod tests {
             ..derived::UNITLESS
         };
         assert_eq!(String::from("F/Sv²"), format!("{}", unit));
+
+        assert_eq!("rad/s", (derived::RADIAN / derived::SECOND).to_string());
+        assert_eq!("cd⋅sr", (derived::CANDELA * derived::STERADIAN).to_string());
+        assert_eq!("lm/m²", (derived::LUMEN / derived::METER_SQ).to_string());
+        assert_eq!("DerivedUnit(lumen)", format!("{:?}", derived::LUMEN));
     }
 
     #[test]
@@ -394,6 +514,15 @@ mod tests {
             String::from("DerivedUnit(farad⋅sievert⁻²)"),
             format!("{:?}", unit)
         );
+        // A -1 exponent used to be written like a 1, losing its sign
+        assert_ne!(
+            "DerivedUnit(hertz)",
+            format!("{:?}", derived::HERTZ.pow(-1))
+        );
+        assert_eq!(
+            "DerivedUnit(hertz⁻¹)",
+            format!("{:?}", derived::HERTZ.pow(-1))
+        );
     }
 
     #[test]
@@ -422,4 +551,82 @@ mod tests {
         };
         assert_eq!(String::from("2 Bq"), format!("{}", two_seconds));
     }
+
+    #[test]
+    fn test_prefixed_display() {
+        use crate::prefix::Prefix;
+
+        let force = derived::DerivedValue::newton(3000.0);
+        assert_eq!("3 kN", force.display_with_prefix(Prefix::Kilo).to_string());
+
+        let rate = derived::DerivedValue {
+            unit: derived::METER / derived::SECOND,
+            number: 0.002,
+        };
+        assert_eq!(
+            "2 mm/s",
+            rate.display_with_prefix(Prefix::Milli).to_string()
+        );
+
+        let frequency = base::BaseValue {
+            unit: base::HERTZ,
+            number: 2000.0,
+        };
+        assert_eq!(
+            "2 /ms",
+            frequency.display_with_prefix(Prefix::Milli).to_string()
+        );
+
+        let area = base::BaseValue {
+            unit: base::METER_SQ,
+            number: 3e6,
+        };
+        assert_eq!("3 km²", area.display_with_prefix(Prefix::Kilo).to_string());
+
+        let mass = base::BaseValue::kilograms(0.005);
+        assert_eq!(
+            "5000 mg",
+            mass.display_with_prefix(Prefix::Milli).to_string()
+        );
+        assert_eq!(
+            "0.005 kg",
+            mass.display_with_prefix(Prefix::Kilo).to_string()
+        );
+
+        // Integers fall back to the plain display when they can't be scaled
+        let time = base::BaseValue::seconds(3);
+        assert_eq!("3 s", time.display_with_prefix(Prefix::Kilo).to_string());
+        assert_eq!(
+            "3000 ms",
+            time.display_with_prefix(Prefix::Milli).to_string()
+        );
+    }
+
+    #[test]
+    fn test_fractional_display() {
+        use crate::{exponents::FractionalExponent, prefix::Prefix};
+
+        let root_hertz = derived::DerivedUnit::<FractionalExponent>::from(derived::HERTZ).root(2);
+        let volt = derived::DerivedUnit::<FractionalExponent>::from(derived::VOLT);
+        let density = derived::DerivedValue {
+            unit: volt / root_hertz,
+            number: 3e-9,
+        };
+        assert_eq!("V/Hz⁰⋅⁵", density.unit.to_string());
+        assert_eq!(
+            "3 nV/Hz⁰⋅⁵",
+            density.display_with_prefix(Prefix::Nano).to_string()
+        );
+
+        let noise = base::BaseValue {
+            unit: base::BaseUnit::<FractionalExponent>::from(base::METER).root(2),
+            number: 2.0,
+        };
+        assert_eq!("2 m⁰⋅⁵", noise.to_string());
+        // Prefixes can't be raised to fractional powers
+        assert_eq!(
+            "2 m⁰⋅⁵",
+            noise.display_with_prefix(Prefix::Kilo).to_string()
+        );
+    }
 }
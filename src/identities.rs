@@ -1,144 +1,43 @@
-use crate::{base, derived::*};
+use crate::derived::*;
 
-pub fn basic_identities() -> [DerivedUnit; 19] {
-    let hertz_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::HERTZ),
-        hertz: 1,
-        ..UNITLESS
-    };
-
-    let newton_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::NEWTON),
-        newton: 1,
-        ..UNITLESS
-    };
-
-    let pascal_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::PASCAL),
-        pascal: 1,
-        ..UNITLESS
-    };
-
-    let joule_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::JOULE),
-        joule: 1,
-        ..UNITLESS
-    };
-
-    let watt_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::WATT),
-        watt: 1,
-        ..UNITLESS
-    };
-
-    let coulomb_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::COULOMB),
-        coulomb: 1,
-        ..UNITLESS
-    };
-
-    let volt_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::VOLT),
-        volt: 1,
-        ..UNITLESS
-    };
-
-    let farad_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::FARAD),
-        farad: 1,
-        ..UNITLESS
-    };
-
-    let ohm_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::OHM),
-        ohm: 1,
-        ..UNITLESS
-    };
-
-    let siemens_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::SIEMENS),
-        siemens: 1,
-        ..UNITLESS
-    };
-
-    let weber_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::WEBER),
-        weber: 1,
-        ..UNITLESS
-    };
-
-    let tesla_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::TESLA),
-        tesla: 1,
-        ..UNITLESS
-    };
-
-    let henry_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::HENRY),
-        henry: 1,
-        ..UNITLESS
-    };
-
-    let lux_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::LUX),
-        lux: 1,
-        ..UNITLESS
-    };
-
-    let becquerel_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::BECQUEREL),
-        becquerel: 1,
-        ..UNITLESS
-    };
-
-    let gray_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::GRAY),
-        gray: 1,
-        ..UNITLESS
-    };
-
-    let sievert_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::SIEVERT),
-        sievert: 1,
-        ..UNITLESS
-    };
-
-    let katal_identity: DerivedUnit = DerivedUnit {
-        base: base::UNITLESS.divide(base::KATAL),
-        katal: 1,
-        ..UNITLESS
-    };
+/// The named unit divided by what it is in base units (e.g. `N⋅s²/(kg⋅m)`), which is unitless
+fn identity(named: NamedUnit) -> DerivedUnit {
+    named.unit().divide(named.definition())
+}
 
+pub fn basic_identities() -> [DerivedUnit; 20] {
     [
         // Energy-related
-        joule_identity,
-        watt_identity,
-        newton_identity,
-        pascal_identity,
+        NamedUnit::Joule,
+        NamedUnit::Watt,
+        NamedUnit::Newton,
+        NamedUnit::Pascal,
         // Ampere-derived
-        coulomb_identity,
-        volt_identity,
-        farad_identity,
-        ohm_identity,
-        siemens_identity,
-        weber_identity,
-        henry_identity,
-        tesla_identity,
-        henry_identity,
+        NamedUnit::Coulomb,
+        NamedUnit::Volt,
+        NamedUnit::Farad,
+        NamedUnit::Ohm,
+        NamedUnit::Siemens,
+        NamedUnit::Weber,
+        NamedUnit::Henry,
+        NamedUnit::Tesla,
+        NamedUnit::Henry,
         // Time-derived
-        hertz_identity,
-        becquerel_identity, // Redundant
-        sievert_identity,
-        gray_identity, // Redundant
+        NamedUnit::Hertz,
+        NamedUnit::Becquerel, // Redundant
+        NamedUnit::Sievert,
+        NamedUnit::Gray, // Redundant
         // Candela-derive
-        lux_identity,
+        NamedUnit::Lumen,
+        NamedUnit::Lux,
         // mole-derived
-        katal_identity,
+        NamedUnit::Katal,
     ]
+    .map(identity)
 }
 
 #[cfg(test)]
-fn extra_identities() -> [DerivedUnit; 25] {
+fn extra_identities() -> [DerivedUnit; 27] {
     [
         // identities between derived units
         PASCAL.divide(NEWTON.divide(METER_SQ)),
@@ -167,6 +66,8 @@ fn extra_identities() -> [DerivedUnit; 25] {
         HENRY.divide(WEBER.divide(AMPERE)),
         GRAY.divide(JOULE.divide(KILOGRAM)),
         SIEVERT.divide(JOULE.divide(KILOGRAM)),
+        LUMEN.divide(CANDELA.multiply(STERADIAN)),
+        LUX.divide(LUMEN.divide(METER_SQ)),
     ]
 }
 
@@ -0,0 +1,398 @@
+//! Kinds of quantities (e.g. absorbed dose and dose equivalent)
+//! which distinguish values that have the same dimension but shouldn't be mixed.
+//!
+//! Kinds are named after ISO 80000 and a `KindValue` pairs a value with its kind
+//! so that adding a dose in Gy to a dose in Sv is an error unless one of them
+//! is explicitly cast to the other's kind.
+//!
+//! ```
+//! use physical_units::{derived::DerivedValue, kind};
+//!
+//! let absorbed = DerivedValue::grays(1.0).with_kind(kind::ABSORBED_DOSE).unwrap();
+//! let equivalent = DerivedValue::sieverts(2.0).with_kind(kind::DOSE_EQUIVALENT).unwrap();
+//! assert!((absorbed + equivalent).is_err());
+//!
+//! let cast = absorbed.cast(kind::DOSE_EQUIVALENT).unwrap();
+//! assert!((cast + equivalent).is_ok());
+//! ```
+
+use core::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use thiserror::Error;
+
+use crate::{
+    base::{self, BaseUnit},
+    derived::{self, DerivedUnit, DerivedValue, UnitMismatch},
+};
+
+/// A kind of quantity with a name and the coherent SI unit for it.
+///
+/// Two kinds are only compatible if they're equal,
+/// even if their units have the same dimension (e.g. torque and energy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Kind {
+    name: &'static str,
+    unit: DerivedUnit,
+}
+
+impl Kind {
+    pub const fn new(name: &'static str, unit: DerivedUnit) -> Self {
+        Self { name, unit }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn unit(&self) -> DerivedUnit {
+        self.unit
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// ISO 80000-3 length
+pub const LENGTH: Kind = Kind::new("length", derived::METER);
+/// ISO 80000-3 area
+pub const AREA: Kind = Kind::new("area", derived::METER_SQ);
+/// ISO 80000-3 duration
+pub const DURATION: Kind = Kind::new("duration", derived::SECOND);
+/// ISO 80000-3 speed
+pub const SPEED: Kind = Kind::new(
+    "speed",
+    DerivedUnit {
+        base: BaseUnit {
+            meter: 1,
+            second: -1,
+            ..base::UNITLESS
+        },
+        ..derived::UNITLESS
+    },
+);
+/// ISO 80000-3 frequency
+pub const FREQUENCY: Kind = Kind::new("frequency", derived::HERTZ);
+/// ISO 80000-4 mass
+pub const MASS: Kind = Kind::new("mass", derived::KILOGRAM);
+/// ISO 80000-4 force
+pub const FORCE: Kind = Kind::new("force", derived::NEWTON);
+/// ISO 80000-4 pressure
+pub const PRESSURE: Kind = Kind::new("pressure", derived::PASCAL);
+/// ISO 80000-4 torque
+pub const TORQUE: Kind = Kind::new(
+    "torque",
+    DerivedUnit {
+        base: base::METER,
+        newton: 1,
+        ..derived::UNITLESS
+    },
+);
+/// ISO 80000-4 energy
+pub const ENERGY: Kind = Kind::new("energy", derived::JOULE);
+/// ISO 80000-4 power
+pub const POWER: Kind = Kind::new("power", derived::WATT);
+/// ISO 80000-5 thermodynamic temperature
+pub const THERMODYNAMIC_TEMPERATURE: Kind = Kind::new("thermodynamic temperature", derived::KELVIN);
+/// ISO 80000-6 electric current
+pub const ELECTRIC_CURRENT: Kind = Kind::new("electric current", derived::AMPERE);
+/// ISO 80000-6 electric charge
+pub const ELECTRIC_CHARGE: Kind = Kind::new("electric charge", derived::COULOMB);
+/// ISO 80000-6 voltage
+pub const VOLTAGE: Kind = Kind::new("voltage", derived::VOLT);
+/// ISO 80000-6 capacitance
+pub const CAPACITANCE: Kind = Kind::new("capacitance", derived::FARAD);
+/// ISO 80000-6 resistance
+pub const RESISTANCE: Kind = Kind::new("resistance", derived::OHM);
+/// ISO 80000-6 conductance
+pub const CONDUCTANCE: Kind = Kind::new("conductance", derived::SIEMENS);
+/// ISO 80000-6 magnetic flux
+pub const MAGNETIC_FLUX: Kind = Kind::new("magnetic flux", derived::WEBER);
+/// ISO 80000-6 magnetic flux density
+pub const MAGNETIC_FLUX_DENSITY: Kind = Kind::new("magnetic flux density", derived::TESLA);
+/// ISO 80000-6 inductance
+pub const INDUCTANCE: Kind = Kind::new("inductance", derived::HENRY);
+/// ISO 80000-7 luminous intensity
+pub const LUMINOUS_INTENSITY: Kind = Kind::new("luminous intensity", derived::CANDELA);
+/// ISO 80000-7 luminous flux
+pub const LUMINOUS_FLUX: Kind = Kind::new("luminous flux", derived::LUMEN);
+/// ISO 80000-7 illuminance
+pub const ILLUMINANCE: Kind = Kind::new("illuminance", derived::LUX);
+/// ISO 80000-9 amount of substance
+pub const AMOUNT_OF_SUBSTANCE: Kind = Kind::new("amount of substance", derived::MOLE);
+/// ISO 80000-9 catalytic activity
+pub const CATALYTIC_ACTIVITY: Kind = Kind::new("catalytic activity", derived::KATAL);
+/// ISO 80000-10 activity
+pub const ACTIVITY: Kind = Kind::new("activity", derived::BECQUEREL);
+/// ISO 80000-10 absorbed dose
+pub const ABSORBED_DOSE: Kind = Kind::new("absorbed dose", derived::GRAY);
+/// ISO 80000-10 dose equivalent
+pub const DOSE_EQUIVALENT: Kind = Kind::new("dose equivalent", derived::SIEVERT);
+
+/// The registry of kinds which can be looked up by name.
+pub const KINDS: [Kind; 29] = [
+    LENGTH,
+    AREA,
+    DURATION,
+    SPEED,
+    FREQUENCY,
+    MASS,
+    FORCE,
+    PRESSURE,
+    TORQUE,
+    ENERGY,
+    POWER,
+    THERMODYNAMIC_TEMPERATURE,
+    ELECTRIC_CURRENT,
+    ELECTRIC_CHARGE,
+    VOLTAGE,
+    CAPACITANCE,
+    RESISTANCE,
+    CONDUCTANCE,
+    MAGNETIC_FLUX,
+    MAGNETIC_FLUX_DENSITY,
+    INDUCTANCE,
+    LUMINOUS_INTENSITY,
+    LUMINOUS_FLUX,
+    ILLUMINANCE,
+    AMOUNT_OF_SUBSTANCE,
+    CATALYTIC_ACTIVITY,
+    ACTIVITY,
+    ABSORBED_DOSE,
+    DOSE_EQUIVALENT,
+];
+
+/// Looks up a kind by its ISO 80000 name (e.g. `"absorbed dose"`).
+pub fn lookup_name(name: &str) -> Option<Kind> {
+    KINDS.into_iter().find(|kind| kind.name == name)
+}
+
+/// All of the registered kinds which have the same dimension as the unit.
+pub fn kinds_of(unit: DerivedUnit) -> impl Iterator<Item = Kind> {
+    KINDS.into_iter().filter(move |kind| kind.unit == unit)
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KindError {
+    #[error("Kind '{lhs}' can't be combined with '{rhs}'")]
+    Mismatch { lhs: Kind, rhs: Kind },
+    #[error("Unit '{unit}' isn't a unit of '{kind}' which is measured in '{}'", kind.unit)]
+    Dimension { unit: DerivedUnit, kind: Kind },
+    #[error(transparent)]
+    Unit(#[from] UnitMismatch<i8>),
+}
+
+/// A value tagged with the kind of quantity it is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KindValue<Number> {
+    value: DerivedValue<Number>,
+    kind: Kind,
+}
+
+impl<Number> KindValue<Number> {
+    /// Tags the value with a kind, failing if its unit has a different dimension.
+    pub fn new(value: DerivedValue<Number>, kind: Kind) -> Result<Self, KindError> {
+        if value.unit != kind.unit {
+            return Err(KindError::Dimension {
+                unit: value.unit,
+                kind,
+            });
+        }
+        Ok(Self { value, kind })
+    }
+
+    pub const fn value(&self) -> &DerivedValue<Number> {
+        &self.value
+    }
+
+    pub const fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Removes the kind.
+    pub fn into_value(self) -> DerivedValue<Number> {
+        self.value
+    }
+
+    /// Deliberately changes the kind (e.g. from absorbed dose to dose equivalent)
+    /// and writes the value in the kind's unit, failing only if the dimensions are different.
+    pub fn cast(self, kind: Kind) -> Result<Self, KindError> {
+        let value = Self::new(self.value, kind)?.value;
+        Ok(Self {
+            value: DerivedValue {
+                unit: kind.unit,
+                number: value.number,
+            },
+            kind,
+        })
+    }
+
+    fn check_kind(&self, other: &Self) -> Result<(), KindError> {
+        if self.kind == other.kind {
+            Ok(())
+        } else {
+            Err(KindError::Mismatch {
+                lhs: self.kind,
+                rhs: other.kind,
+            })
+        }
+    }
+}
+
+impl<Number> DerivedValue<Number> {
+    /// Tags the value with a kind, see `KindValue::new`.
+    pub fn with_kind(self, kind: Kind) -> Result<KindValue<Number>, KindError> {
+        KindValue::new(self, kind)
+    }
+}
+
+impl<Number> fmt::Display for KindValue<Number>
+where
+    Number: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.value, self.kind)
+    }
+}
+
+impl<Number> Neg for KindValue<Number>
+where
+    Number: Neg<Output = Number>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            value: -self.value,
+            kind: self.kind,
+        }
+    }
+}
+
+/// Adds values of the same kind.
+impl<Number> Add for KindValue<Number>
+where
+    Number: Add<Output = Number>,
+{
+    type Output = Result<Self, KindError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check_kind(&rhs)?;
+        Ok(Self {
+            value: (self.value + rhs.value)?,
+            kind: self.kind,
+        })
+    }
+}
+
+/// Subtracts values of the same kind.
+impl<Number> Sub for KindValue<Number>
+where
+    Number: Sub<Output = Number>,
+{
+    type Output = Result<Self, KindError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.check_kind(&rhs)?;
+        Ok(Self {
+            value: (self.value - rhs.value)?,
+            kind: self.kind,
+        })
+    }
+}
+
+/// Multiplying values produces a value without a kind
+/// since the kind of the product isn't known.
+impl<Number> Mul for KindValue<Number>
+where
+    Number: Mul<Output = Number>,
+{
+    type Output = DerivedValue<Number>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.value * rhs.value
+    }
+}
+
+/// Dividing values produces a value without a kind
+/// since the kind of the quotient isn't known.
+impl<Number> Div for KindValue<Number>
+where
+    Number: Div<Output = Number>,
+{
+    type Output = DerivedValue<Number>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.value / rhs.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_arithmetic() {
+        let absorbed = DerivedValue::grays(1.0).with_kind(ABSORBED_DOSE).unwrap();
+        let equivalent = DerivedValue::sieverts(2.0)
+            .with_kind(DOSE_EQUIVALENT)
+            .unwrap();
+        assert_eq!(
+            Err(KindError::Mismatch {
+                lhs: ABSORBED_DOSE,
+                rhs: DOSE_EQUIVALENT
+            }),
+            absorbed + equivalent
+        );
+        assert!((equivalent - absorbed).is_err());
+
+        let sum = (absorbed.cast(DOSE_EQUIVALENT).unwrap() + equivalent).unwrap();
+        assert_eq!(DOSE_EQUIVALENT, sum.kind());
+        assert_eq!(&DerivedValue::sieverts(3.0), sum.value());
+        assert_eq!("3 Sv (dose equivalent)", sum.to_string());
+
+        let torque = DerivedValue::new(2.0, derived::NEWTON * derived::METER)
+            .with_kind(TORQUE)
+            .unwrap();
+        let energy = DerivedValue::joules(2.0).with_kind(ENERGY).unwrap();
+        assert!((torque + energy).is_err());
+        assert!((torque + torque).is_ok());
+        assert_eq!(
+            DerivedValue::new(4.0, derived::JOULE.pow(2)),
+            torque * energy
+        );
+    }
+
+    #[test]
+    fn test_kind_dimensions() {
+        let error = DerivedValue::meters(1.0).with_kind(ENERGY).unwrap_err();
+        assert_eq!(
+            "Unit 'm' isn't a unit of 'energy' which is measured in 'J'",
+            error.to_string()
+        );
+        assert!(
+            DerivedValue::grays(1.0)
+                .with_kind(ABSORBED_DOSE)
+                .unwrap()
+                .cast(ENERGY)
+                .is_err()
+        );
+
+        // Units are compared by dimension so `J` works for torque
+        assert!(DerivedValue::joules(1.0).with_kind(TORQUE).is_ok());
+    }
+
+    #[test]
+    fn test_registry() {
+        assert_eq!(Some(ABSORBED_DOSE), lookup_name("absorbed dose"));
+        assert_eq!(None, lookup_name("dose"));
+        let kinds: Vec<_> = kinds_of(derived::HERTZ).map(|kind| kind.name()).collect();
+        assert_eq!(vec!["frequency", "activity"], kinds);
+        // Steradians are dimensionless so cd and lm are the same unit
+        let kinds: Vec<_> = kinds_of(derived::LUMEN).map(|kind| kind.name()).collect();
+        assert_eq!(vec!["luminous intensity", "luminous flux"], kinds);
+    }
+}
@@ -1,9 +1,24 @@
 pub mod base;
 mod constructors;
+mod compare;
 mod convert;
+mod scalar;
 pub mod derived;
 pub mod exponents;
 mod format;
+pub mod parse;
+pub mod prefix;
+pub mod scaled;
+pub mod customary;
+pub mod cgs;
+pub mod constants;
+pub mod natural;
+pub mod reference;
 pub mod identities;
+pub mod kind;
+pub mod temperature;
+pub mod point;
 pub mod sqrt;
-mod convert_exponents;
\ No newline at end of file
+pub mod simplify;
+mod convert_exponents;
+mod angle;
\ No newline at end of file
@@ -0,0 +1,336 @@
+//! Natural units, where ħ = c = 1 so that every dimension is a power of energy (in eV),
+//! and Planck units, where ħ = c = G = k_B = 1 so that every value is a number.
+//!
+//! With ħ = c = 1 a mass is an energy (E = mc²), a length and a time are inverse energies
+//! (ħc/E and ħ/E) and, with k_B = 1 as well, a temperature is an energy (k_B T).
+//! So kg^a⋅m^b⋅s^c⋅K^d is eV^(a - b - c + d).
+//! Amperes, candelas, and moles can't be written in either system.
+//!
+//! ```
+//! use physical_units::{constants, natural::System};
+//!
+//! let mass = System::HbarC.to_natural(&constants::ELECTRON_MASS.value()).unwrap();
+//! assert_eq!(1, mass.power());
+//! assert!((mass.number() - 510_998.95).abs() < 0.01);
+//!
+//! let back = System::HbarC.from_natural(&mass, physical_units::derived::KILOGRAM).unwrap();
+//! assert!((back.number() / constants::ELECTRON_MASS.value().number() - 1.0).abs() < 1e-12);
+//! ```
+
+use core::fmt;
+
+use thiserror::Error;
+
+use crate::{
+    base::BaseUnit,
+    constants,
+    derived::{DerivedUnit, DerivedValue},
+    exponents::{UnitError, UnitExponent},
+};
+
+/// The constants set to 1 in Planck units
+const PLANCK_CONSTANTS: &str = "ħ = c = G = k_B = 1";
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum NaturalError {
+    #[error("Unit '{unit}' can't be written in units where {constants}")]
+    Dimension {
+        unit: DerivedUnit,
+        /// The constants set to 1 (e.g. `ħ = c = 1`)
+        constants: &'static str,
+    },
+    #[error("Unit '{unit}' is eV^{expected} in units where {constants}, not eV^{actual}")]
+    Power {
+        unit: DerivedUnit,
+        constants: &'static str,
+        expected: i8,
+        actual: i8,
+    },
+    #[error(transparent)]
+    Unit(#[from] UnitError),
+}
+
+/// A system of natural units, where energies are measured in eV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum System {
+    /// ħ = c = 1, which can't write temperatures
+    HbarC,
+    /// ħ = c = k_B = 1, where temperatures are energies
+    HbarCBoltzmann,
+}
+
+/// A number of eV^power (e.g. 0.511 MeV is 511000 eV¹ and 1 fm is 5.07×10⁻⁹ eV⁻¹).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NaturalValue {
+    number: f64,
+    power: i8,
+}
+
+impl NaturalValue {
+    pub const fn new(number: f64, power: i8) -> Self {
+        Self { number, power }
+    }
+
+    pub const fn number(&self) -> f64 {
+        self.number
+    }
+
+    /// The power of eV the number is measured in.
+    pub const fn power(&self) -> i8 {
+        self.power
+    }
+}
+
+impl fmt::Display for NaturalValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.power == 0 {
+            write!(f, "{}", self.number)
+        } else {
+            write!(f, "{} eV{}", self.number, self.power.to_parts())
+        }
+    }
+}
+
+impl System {
+    /// The constants set to 1 (e.g. `ħ = c = 1`).
+    pub const fn constants(self) -> &'static str {
+        match self {
+            System::HbarC => "ħ = c = 1",
+            System::HbarCBoltzmann => "ħ = c = k_B = 1",
+        }
+    }
+
+    /// The power of eV that a unit is in this system (e.g. 1 for J, -1 for m and s, and 0 for m/s).
+    pub fn energy_exponent(self, unit: DerivedUnit) -> Result<i8, NaturalError> {
+        let base = check_dimension(unit, self.constants(), self == System::HbarCBoltzmann)?;
+        let power = base
+            .kilogram
+            .checked_sub(base.meter)
+            .and_then(|power| power.checked_sub(base.second))
+            .and_then(|power| power.checked_add(base.kelvin))
+            .ok_or(UnitError::Overflow)?;
+        Ok(power)
+    }
+
+    /// Converts a value to a number of eV^n (e.g. the electron mass is 510999 eV).
+    pub fn to_natural(self, value: &DerivedValue<f64>) -> Result<NaturalValue, NaturalError> {
+        let power = self.energy_exponent(value.unit)?;
+        let scale = scale(&value.unit.to_base(), electronvolts_per_unit());
+        Ok(NaturalValue::new(value.number * scale, power))
+    }
+
+    /// Converts a number of eV^n to a value in `unit`,
+    /// failing if `unit` isn't eV^n in this system (e.g. eV⁻¹ can be in m or s but not kg).
+    pub fn from_natural(
+        self,
+        value: &NaturalValue,
+        unit: DerivedUnit,
+    ) -> Result<DerivedValue<f64>, NaturalError> {
+        let expected = self.energy_exponent(unit)?;
+        if expected != value.power {
+            return Err(NaturalError::Power {
+                unit,
+                constants: self.constants(),
+                expected,
+                actual: value.power,
+            });
+        }
+        let scale = scale(&unit.to_base(), electronvolts_per_unit());
+        Ok(DerivedValue::new(value.number / scale, unit))
+    }
+}
+
+/// Converts a value to a number of Planck units (e.g. the Planck mass is about 2.18×10⁻⁸ kg).
+pub fn to_planck(value: &DerivedValue<f64>) -> Result<f64, NaturalError> {
+    let base = check_dimension(value.unit, PLANCK_CONSTANTS, true)?;
+    Ok(value.number * scale(&base, planck_units_per_unit()))
+}
+
+/// Converts a number of Planck units to a value in `unit`.
+pub fn from_planck(number: f64, unit: DerivedUnit) -> Result<DerivedValue<f64>, NaturalError> {
+    let base = check_dimension(unit, PLANCK_CONSTANTS, true)?;
+    Ok(DerivedValue::new(
+        number / scale(&base, planck_units_per_unit()),
+        unit,
+    ))
+}
+
+/// Fails if the unit has a dimension other than kg, m, s, and (if `kelvin`) K.
+fn check_dimension(
+    unit: DerivedUnit,
+    constants: &'static str,
+    kelvin: bool,
+) -> Result<BaseUnit, NaturalError> {
+    let base = unit.to_base();
+    if base.mole != 0 || base.ampere != 0 || base.candela != 0 || (!kelvin && base.kelvin != 0) {
+        return Err(NaturalError::Dimension { unit, constants });
+    }
+    Ok(base)
+}
+
+/// The product of the scale of each base unit raised to its exponent in `base`.
+fn scale(base: &BaseUnit, [kilogram, meter, second, kelvin]: [f64; 4]) -> f64 {
+    kilogram.powi(base.kilogram.into())
+        * meter.powi(base.meter.into())
+        * second.powi(base.second.into())
+        * kelvin.powi(base.kelvin.into())
+}
+
+/// The number of eV^n in 1 kg, m, s, and K:
+/// 1 kg is c²/e eV, 1 m is e/(ħc) eV⁻¹, 1 s is e/ħ eV⁻¹, and 1 K is k_B/e eV.
+fn electronvolts_per_unit() -> [f64; 4] {
+    let hbar = constants::REDUCED_PLANCK.value().number;
+    let c = constants::SPEED_OF_LIGHT.value().number;
+    let e = constants::ELEMENTARY_CHARGE.value().number;
+    let k = constants::BOLTZMANN.value().number;
+    [c * c / e, e / (hbar * c), e / hbar, k / e]
+}
+
+/// The number of Planck units in 1 kg, m, s, and K, which are the reciprocals of
+/// the Planck mass √(ħc/G), length √(ħG/c³), time √(ħG/c⁵), and temperature √(ħc⁵/G)/k_B.
+fn planck_units_per_unit() -> [f64; 4] {
+    let hbar = constants::REDUCED_PLANCK.value().number;
+    let c = constants::SPEED_OF_LIGHT.value().number;
+    let g = constants::GRAVITATIONAL.value().number;
+    let k = constants::BOLTZMANN.value().number;
+    [
+        (g / (hbar * c)).sqrt(),
+        (c.powi(3) / (hbar * g)).sqrt(),
+        (c.powi(5) / (hbar * g)).sqrt(),
+        k * (g / (hbar * c.powi(5))).sqrt(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::derived::{self, NamedUnit};
+
+    fn assert_close(expected: f64, actual: f64, tolerance: f64) {
+        assert!(
+            (expected - actual).abs() <= tolerance * expected.abs(),
+            "{expected} != {actual}"
+        );
+    }
+
+    #[test]
+    fn test_energy_exponents() {
+        let system = System::HbarC;
+        assert_eq!(Ok(1), system.energy_exponent(derived::JOULE));
+        assert_eq!(Ok(1), system.energy_exponent(derived::KILOGRAM));
+        assert_eq!(Ok(-1), system.energy_exponent(derived::METER));
+        assert_eq!(Ok(2), system.energy_exponent(derived::NEWTON));
+        assert_eq!(Ok(0), system.energy_exponent(derived::RADIAN));
+        assert_eq!(
+            Ok(0),
+            system.energy_exponent(constants::SPEED_OF_LIGHT.value().unit)
+        );
+        assert_eq!(
+            Ok(0),
+            system.energy_exponent(constants::REDUCED_PLANCK.value().unit)
+        );
+
+        for named in NamedUnit::ALL {
+            let unit = named.unit();
+            let base = unit.to_base();
+            if base.ampere == 0 && base.candela == 0 && base.mole == 0 && base.kelvin == 0 {
+                assert!(system.energy_exponent(unit).is_ok(), "{unit}");
+            } else {
+                assert!(system.energy_exponent(unit).is_err(), "{unit}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_natural_conversions() {
+        let system = System::HbarC;
+        let c = system
+            .to_natural(&constants::SPEED_OF_LIGHT.value())
+            .unwrap();
+        assert_eq!(0, c.power());
+        assert_close(1.0, c.number(), 1e-15);
+        let hbar = system
+            .to_natural(&constants::REDUCED_PLANCK.value())
+            .unwrap();
+        assert_close(1.0, hbar.number(), 1e-15);
+
+        // ħc ≈ 197.327 MeV⋅fm
+        let femtometer = system.to_natural(&DerivedValue::meters(1e-15)).unwrap();
+        assert_eq!(-1, femtometer.power());
+        assert_close(1.0 / 197.326_980_4e6, femtometer.number(), 1e-9);
+        assert_eq!("0.5 eV⁻¹", NaturalValue::new(0.5, -1).to_string());
+
+        let seconds = system
+            .from_natural(&NaturalValue::new(1.0, -1), derived::SECOND)
+            .unwrap();
+        assert_close(6.582_119_569e-16, seconds.number, 1e-9);
+
+        let error = system
+            .from_natural(&NaturalValue::new(1.0, -1), derived::KILOGRAM)
+            .unwrap_err();
+        assert_eq!(
+            "Unit 'kg' is eV^1 in units where ħ = c = 1, not eV^-1",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_temperature() {
+        let kelvin = DerivedValue::kelvin(1.0);
+        let error = System::HbarC.to_natural(&kelvin).unwrap_err();
+        assert_eq!(
+            "Unit 'K' can't be written in units where ħ = c = 1",
+            error.to_string()
+        );
+
+        let energy = System::HbarCBoltzmann.to_natural(&kelvin).unwrap();
+        assert_eq!(1, energy.power());
+        assert_close(8.617_333_262e-5, energy.number(), 1e-9);
+    }
+
+    #[test]
+    fn test_unsupported_dimensions() {
+        for unit in [
+            derived::AMPERE,
+            derived::CANDELA,
+            derived::MOLE,
+            derived::COULOMB,
+        ] {
+            for system in [System::HbarC, System::HbarCBoltzmann] {
+                let value = DerivedValue::new(1.0, unit);
+                assert!(
+                    matches!(
+                        system.to_natural(&value),
+                        Err(NaturalError::Dimension { .. })
+                    ),
+                    "{unit}"
+                );
+            }
+            assert!(to_planck(&DerivedValue::new(1.0, unit)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_planck_units() {
+        for constant in [
+            constants::SPEED_OF_LIGHT,
+            constants::REDUCED_PLANCK,
+            constants::GRAVITATIONAL,
+            constants::BOLTZMANN,
+        ] {
+            assert_close(1.0, to_planck(&constant.value()).unwrap(), 1e-12);
+        }
+
+        let mass = from_planck(1.0, derived::KILOGRAM).unwrap();
+        assert_close(2.176_434e-8, mass.number, 1e-5);
+        let length = from_planck(1.0, derived::METER).unwrap();
+        assert_close(1.616_255e-35, length.number, 1e-5);
+        let temperature = from_planck(1.0, derived::KELVIN).unwrap();
+        assert_close(1.416_784e32, temperature.number, 1e-5);
+        assert_close(
+            2.0,
+            to_planck(&DerivedValue::new(2.0 * mass.number, derived::KILOGRAM)).unwrap(),
+            1e-12,
+        );
+    }
+}
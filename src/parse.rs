@@ -0,0 +1,1137 @@
+//! Parsing for the unit and value types.
+//!
+//! Accepts everything the `Display` implementations produce
+//! (e.g. `"kg⋅m/s²"`, `"kg/(m⋅s²)"`, `"m⁰⋅⁵"`) as well as
+//! common ASCII spellings (e.g. `"kg*m/s^2"`, `"kg m s^-2"`, `"m^(1/2)"`).
+//!
+//! Values are a number followed by whitespace and a unit (e.g. `"9.81 m/s²"`)
+//! or just a number when they are unitless.
+//! `FromStr` keeps the number as written, so units with prefixes (e.g. `"3 km"`)
+//! are parsed by `parse_prefixed`, which scales the number.
+//!
+//! Scaled units are either the symbol of a known scaled unit (e.g. `"h"`)
+//! or a unit with prefixes (e.g. `"km/ms"`).
+
+use core::fmt;
+use std::{ops::Range, str::FromStr};
+
+use thiserror::Error;
+
+use crate::{
+    base::{BaseUnit, BaseValue},
+    derived::{DerivedUnit, DerivedValue, NamedUnit},
+    exponents::{ExponentParts, UnitExponent},
+    prefix::{PowerOfTen, Prefix},
+    scaled::{self, Factor, ScaledUnit, ScaledValue},
+};
+
+/// The number of symbols at the start of `SYMBOLS` that are base units.
+const BASE_SYMBOLS: usize = 7;
+
+/// Unit symbols in the same order as the exponents of `DerivedUnit`,
+/// the base units followed by `NamedUnit::ALL`.
+const SYMBOLS: [&str; BASE_SYMBOLS + NamedUnit::ALL.len()] = {
+    let base = ["kg", "m", "s", "mol", "A", "K", "cd"];
+    let mut symbols = [""; BASE_SYMBOLS + NamedUnit::ALL.len()];
+    let mut i = 0;
+    while i < symbols.len() {
+        symbols[i] = if i < BASE_SYMBOLS {
+            base[i]
+        } else {
+            NamedUnit::ALL[i - BASE_SYMBOLS].symbol()
+        };
+        i += 1;
+    }
+    symbols
+};
+
+/// Exponents for each of the `SYMBOLS` followed by the power of ten
+/// from any prefixes (e.g. 1 km² is 10⁶ m²), all counted in quarters so
+/// they're wide enough that intermediate results don't overflow
+/// and fine enough to hold any `FractionalExponent`.
+type Quarters = [i32; SYMBOLS.len() + 1];
+
+/// The index of the power of ten in `Quarters`
+const POWER_OF_TEN: usize = SYMBOLS.len();
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{kind} at {}..{}", .span.start, .span.end)]
+pub struct ParseUnitError {
+    /// The byte range of the input that caused the error
+    pub span: Range<usize>,
+    pub kind: ParseUnitErrorKind,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseUnitErrorKind {
+    #[error("expected {0}")]
+    Expected(Expected),
+    #[error("unknown unit '{0}'")]
+    UnknownUnit(String),
+    #[error("exponent can't be represented")]
+    UnrepresentableExponent,
+    #[error(
+        "unit '{0}' is scaled by a power of ten so it can only be parsed as a scaled unit or with `parse_prefixed`"
+    )]
+    Scaled(String),
+}
+
+/// What the parser was expecting to find when it failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    /// A unit symbol (e.g. `m`), `1`, or a parenthesized unit
+    Unit,
+    /// The digits of an exponent (e.g. `²` or `^2`)
+    Exponent,
+    /// A closing parenthesis
+    ClosingParen,
+    /// A multiplication (`⋅`, `*`, or a space), a division (`/`), or the end of the unit
+    OperatorOrEnd,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Unit => write!(f, "a unit"),
+            Expected::Exponent => write!(f, "an exponent"),
+            Expected::ClosingParen => write!(f, "')'"),
+            Expected::OperatorOrEnd => write!(f, "'⋅', '/', or the end of the unit"),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseValueError<NumberError> {
+    #[error("invalid number at {}..{}: {error}", .span.start, .span.end)]
+    Number {
+        /// The byte range of the input that should have been a number
+        span: Range<usize>,
+        error: NumberError,
+    },
+    #[error(transparent)]
+    Unit(#[from] ParseUnitError),
+    #[error("number at {}..{} can't be represented after applying the unit's prefixes", .span.start, .span.end)]
+    UnrepresentableNumber {
+        /// The byte range of the number
+        span: Range<usize>,
+    },
+}
+
+/// Units that can be built from parsed exponents
+trait FromQuarters: Sized {
+    /// Ignores the power of ten and reports errors
+    /// as spanning all of the `input` that was parsed.
+    fn from_quarters(q: &Quarters, input: &str) -> Result<Self, ParseUnitError>;
+}
+
+impl<ExponentType> FromStr for DerivedUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unprefixed(s)
+    }
+}
+
+/// Derived unit symbols (e.g. `N`) are accepted and expanded into base units.
+impl<ExponentType> FromStr for BaseUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unprefixed(s)
+    }
+}
+
+fn parse_unprefixed<Unit: FromQuarters>(input: &str) -> Result<Unit, ParseUnitError> {
+    let parsed = parse_quarters(input)?;
+    if parsed.quarters[POWER_OF_TEN] != 0 {
+        let span = parsed.prefixed_symbol.unwrap_or(0..input.len());
+        return Err(ParseUnitError {
+            kind: ParseUnitErrorKind::Scaled(input[span.clone()].to_string()),
+            span,
+        });
+    }
+    Unit::from_quarters(&parsed.quarters, input)
+}
+
+impl FromStr for ScaledUnit {
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if let Some(unit) = scaled::lookup_symbol(trimmed) {
+            return Ok(unit.clone());
+        }
+        let parsed = parse_quarters(s)?;
+        let unit = DerivedUnit::from_quarters(&parsed.quarters, s)?;
+        let power = parsed.quarters[POWER_OF_TEN];
+        let factor = if power % 4 == 0 {
+            Factor::checked_power_of_ten(power / 4)
+        } else {
+            None
+        };
+        let Some(factor) = factor else {
+            return Err(ParseUnitError {
+                span: 0..s.len(),
+                kind: ParseUnitErrorKind::UnrepresentableExponent,
+            });
+        };
+        Ok(ScaledUnit {
+            symbol: trimmed.to_string().into(),
+            unit,
+            factor,
+            offset: Factor::ZERO,
+        })
+    }
+}
+
+impl<ExponentType> FromQuarters for DerivedUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    fn from_quarters(q: &Quarters, s: &str) -> Result<Self, ParseUnitError> {
+        let e = |i: usize| exponent_from_quarters(q[i], s);
+        let mut named = [ExponentType::ZERO; NamedUnit::ALL.len()];
+        for (i, n) in named.iter_mut().enumerate() {
+            *n = e(BASE_SYMBOLS + i)?;
+        }
+        Ok(DerivedUnit::from_parts(
+            BaseUnit {
+                kilogram: e(0)?,
+                meter: e(1)?,
+                second: e(2)?,
+                mole: e(3)?,
+                ampere: e(4)?,
+                kelvin: e(5)?,
+                candela: e(6)?,
+            },
+            named,
+        ))
+    }
+}
+
+impl<ExponentType> FromQuarters for BaseUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    fn from_quarters(q: &Quarters, s: &str) -> Result<Self, ParseUnitError> {
+        let overflow = || ParseUnitError {
+            span: 0..s.len(),
+            kind: ParseUnitErrorKind::UnrepresentableExponent,
+        };
+
+        let mut base = [0i32; BASE_SYMBOLS];
+        base.copy_from_slice(&q[..BASE_SYMBOLS]);
+        for (named, n) in NamedUnit::ALL.iter().zip(&q[BASE_SYMBOLS..POWER_OF_TEN]) {
+            for (total, per_unit) in base.iter_mut().zip(base_exponents(named.definition().base)) {
+                *total = n
+                    .checked_mul(per_unit as i32)
+                    .and_then(|x| total.checked_add(x))
+                    .ok_or_else(overflow)?;
+            }
+        }
+
+        let e = |i: usize| exponent_from_quarters(base[i], s);
+        Ok(BaseUnit {
+            kilogram: e(0)?,
+            meter: e(1)?,
+            second: e(2)?,
+            mole: e(3)?,
+            ampere: e(4)?,
+            kelvin: e(5)?,
+            candela: e(6)?,
+        })
+    }
+}
+
+/// Units scaled by a power of ten (e.g. `km` or `g`) are rejected, see `parse_prefixed`.
+impl<Number, ExponentType> FromStr for DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: FromStr,
+{
+    type Err = ParseValueError<Number::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, unit) = parse_value(s)?;
+        Ok(DerivedValue { unit, number })
+    }
+}
+
+/// Units scaled by a power of ten (e.g. `km` or `g`) are rejected, see `parse_prefixed`.
+impl<Number, ExponentType> FromStr for BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: FromStr,
+{
+    type Err = ParseValueError<Number::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, unit) = parse_value(s)?;
+        Ok(BaseValue { unit, number })
+    }
+}
+
+impl<Number, ExponentType> DerivedValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: FromStr + PowerOfTen,
+{
+    /// Parses a value like `FromStr` and applies the unit's prefixes to the number,
+    /// so `"3 kN"` is 3000 N.
+    pub fn parse_prefixed(s: &str) -> Result<Self, ParseValueError<Number::Err>> {
+        let (number, unit) = parse_prefixed_value(s)?;
+        Ok(DerivedValue { unit, number })
+    }
+}
+
+impl<Number, ExponentType> BaseValue<Number, ExponentType>
+where
+    ExponentType: UnitExponent,
+    Number: FromStr + PowerOfTen,
+{
+    /// Parses a value like `FromStr` and applies the unit's prefixes to the number,
+    /// so `"2 km"` is 2000 m and `"5 g"` is 0.005 kg.
+    pub fn parse_prefixed(s: &str) -> Result<Self, ParseValueError<Number::Err>> {
+        let (number, unit) = parse_prefixed_value(s)?;
+        Ok(BaseValue { unit, number })
+    }
+}
+
+/// A value in a scaled unit (e.g. `14.7 psi`), keeping the number as written.
+impl<Number> FromStr for ScaledValue<Number>
+where
+    Number: FromStr,
+{
+    type Err = ParseValueError<Number::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (number, end) = parse_number(s)?;
+        let unit = s[end..]
+            .parse()
+            .map_err(|error: ParseUnitError| ParseUnitError {
+                span: error.span.start + end..error.span.end + end,
+                kind: error.kind,
+            })?;
+        Ok(ScaledValue::new(number, unit))
+    }
+}
+
+/// Parses the number at the start of a value, up to the first whitespace after it,
+/// and returns it with the index where the unit starts.
+fn parse_number<Number>(input: &str) -> Result<(Number, usize), ParseValueError<Number::Err>>
+where
+    Number: FromStr,
+{
+    let start = input.len() - input.trim_start().len();
+    let end = input[start..]
+        .find(char::is_whitespace)
+        .map_or(input.len(), |i| start + i);
+
+    let number = input[start..end]
+        .parse()
+        .map_err(|error| ParseValueError::Number {
+            span: start..end,
+            error,
+        })?;
+    Ok((number, end))
+}
+
+/// A value whose unit was parsed with the power of ten from its prefixes
+struct ScaledParts<Number, Unit> {
+    number: Number,
+    unit: Unit,
+    /// The power of ten in quarters
+    power: i32,
+    number_span: Range<usize>,
+    /// The span of the first symbol scaled by a power of ten (e.g. `km` or `g`)
+    prefixed_symbol: Option<Range<usize>>,
+}
+
+/// Splits a value into its number and unit at the first whitespace
+/// after the number and parses each half.
+fn parse_scaled_parts<Number, Unit>(
+    input: &str,
+) -> Result<ScaledParts<Number, Unit>, ParseValueError<Number::Err>>
+where
+    Number: FromStr,
+    Unit: FromQuarters + Default,
+{
+    let start = input.len() - input.trim_start().len();
+    let (number, end): (Number, _) = parse_number(input)?;
+
+    let rest = &input[end..];
+    if rest.trim().is_empty() {
+        return Ok(ScaledParts {
+            number,
+            unit: Unit::default(),
+            power: 0,
+            number_span: start..end,
+            prefixed_symbol: None,
+        });
+    }
+    let offset = |error: ParseUnitError| ParseUnitError {
+        span: error.span.start + end..error.span.end + end,
+        kind: error.kind,
+    };
+    let parsed = parse_quarters(rest).map_err(offset)?;
+    let unit = Unit::from_quarters(&parsed.quarters, rest).map_err(offset)?;
+    Ok(ScaledParts {
+        number,
+        unit,
+        power: parsed.quarters[POWER_OF_TEN],
+        number_span: start..end,
+        prefixed_symbol: parsed
+            .prefixed_symbol
+            .map(|span| span.start + end..span.end + end),
+    })
+}
+
+/// Parses a value whose unit isn't scaled by a power of ten,
+/// since applying prefixes to the number needs `PowerOfTen`.
+fn parse_value<Number, Unit>(input: &str) -> Result<(Number, Unit), ParseValueError<Number::Err>>
+where
+    Number: FromStr,
+    Unit: FromQuarters + Default,
+{
+    let parts: ScaledParts<Number, Unit> = parse_scaled_parts(input)?;
+    if parts.power != 0 {
+        let span = parts
+            .prefixed_symbol
+            .unwrap_or(parts.number_span.end..input.len());
+        return Err(ParseUnitError {
+            kind: ParseUnitErrorKind::Scaled(input[span.clone()].trim().to_string()),
+            span,
+        }
+        .into());
+    }
+    Ok((parts.number, parts.unit))
+}
+
+/// Parses a value and applies any prefixes to the number.
+fn parse_prefixed_value<Number, Unit>(
+    input: &str,
+) -> Result<(Number, Unit), ParseValueError<Number::Err>>
+where
+    Number: FromStr + PowerOfTen,
+    Unit: FromQuarters + Default,
+{
+    let parts: ScaledParts<Number, Unit> = parse_scaled_parts(input)?;
+    let number = if parts.power % 4 == 0 {
+        parts.number.checked_mul_power_of_ten(parts.power / 4)
+    } else {
+        None
+    };
+    let number = number.ok_or(ParseValueError::UnrepresentableNumber {
+        span: parts.number_span,
+    })?;
+    Ok((number, parts.unit))
+}
+
+fn base_exponents(unit: BaseUnit) -> [i8; BASE_SYMBOLS] {
+    [
+        unit.kilogram,
+        unit.meter,
+        unit.second,
+        unit.mole,
+        unit.ampere,
+        unit.kelvin,
+        unit.candela,
+    ]
+}
+
+fn exponent_from_quarters<ExponentType: UnitExponent>(
+    quarters: i32,
+    input: &str,
+) -> Result<ExponentType, ParseUnitError> {
+    let abs = quarters.unsigned_abs();
+    u8::try_from(abs / 4)
+        .ok()
+        .and_then(|whole_part| {
+            ExponentType::from_parts(ExponentParts {
+                sign_positive: quarters >= 0,
+                whole_part,
+                percent_part: (abs % 4) as u8 * 25,
+            })
+        })
+        .ok_or(ParseUnitError {
+            span: 0..input.len(),
+            kind: ParseUnitErrorKind::UnrepresentableExponent,
+        })
+}
+
+/// Converts `numerator / denominator` into quarters if it's exact.
+fn rational_to_quarters(numerator: i64, denominator: i64) -> Option<i32> {
+    let scaled = numerator.checked_mul(4)?;
+    if denominator == 0 || scaled % denominator != 0 {
+        return None;
+    }
+    i32::try_from(scaled / denominator).ok()
+}
+
+/// Converts a decimal `whole.fraction` into quarters if it's exact.
+fn decimal_to_quarters(
+    whole: Option<i64>,
+    fraction: Option<i64>,
+    fraction_digits: u32,
+) -> Option<i32> {
+    let denominator = 10i64.checked_pow(fraction_digits)?;
+    let numerator = whole?.checked_mul(denominator)?.checked_add(fraction?)?;
+    rational_to_quarters(numerator, denominator)
+}
+
+fn superscript_digit(c: char) -> Option<i64> {
+    match c {
+        '⁰' => Some(0),
+        '¹' => Some(1),
+        '²' => Some(2),
+        '³' => Some(3),
+        '⁴' => Some(4),
+        '⁵' => Some(5),
+        '⁶' => Some(6),
+        '⁷' => Some(7),
+        '⁸' => Some(8),
+        '⁹' => Some(9),
+        _ => None,
+    }
+}
+
+fn vulgar_fraction_quarters(c: char) -> Option<i32> {
+    match c {
+        '¼' => Some(1),
+        '½' => Some(2),
+        '¾' => Some(3),
+        _ => None,
+    }
+}
+
+fn is_multiply(c: char) -> bool {
+    matches!(c, '⋅' | '·' | '*')
+}
+
+struct ParsedUnit {
+    quarters: Quarters,
+    /// The span of the first symbol scaled by a power of ten (e.g. `km` or `g`)
+    prefixed_symbol: Option<Range<usize>>,
+}
+
+fn parse_quarters(input: &str) -> Result<ParsedUnit, ParseUnitError> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        prefixed_symbol: None,
+    };
+    let quarters = parser.product()?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.expected(Expected::OperatorOrEnd));
+    }
+    Ok(ParsedUnit {
+        quarters,
+        prefixed_symbol: parser.prefixed_symbol,
+    })
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    prefixed_symbol: Option<Range<usize>>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.input[self.pos..].chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns whether any whitespace was skipped
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+        self.pos != start
+    }
+
+    fn error(&self, span: Range<usize>, kind: ParseUnitErrorKind) -> ParseUnitError {
+        ParseUnitError { span, kind }
+    }
+
+    fn expected(&self, expected: Expected) -> ParseUnitError {
+        let end = self.pos + self.peek().map_or(0, char::len_utf8);
+        self.error(self.pos..end, ParseUnitErrorKind::Expected(expected))
+    }
+
+    /// A sequence of terms separated by multiplication and division
+    fn product(&mut self) -> Result<Quarters, ParseUnitError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        // The display of a unit with only negative exponents starts with '/' (e.g. "/s")
+        let mut quarters = if self.peek() == Some('/') {
+            [0; POWER_OF_TEN + 1]
+        } else {
+            self.term()?
+        };
+
+        loop {
+            let skipped_whitespace = self.skip_whitespace();
+            let divide = match self.peek() {
+                Some(c) if is_multiply(c) => {
+                    self.bump();
+                    false
+                }
+                Some('/') => {
+                    self.bump();
+                    true
+                }
+                Some(c) if skipped_whitespace && (c.is_alphabetic() || c == '(') => false,
+                _ => break,
+            };
+            self.skip_whitespace();
+            let term = self.term()?;
+            for (total, n) in quarters.iter_mut().zip(term) {
+                let result = if divide {
+                    total.checked_sub(n)
+                } else {
+                    total.checked_add(n)
+                };
+                *total = result.ok_or_else(|| {
+                    self.error(start..self.pos, ParseUnitErrorKind::UnrepresentableExponent)
+                })?;
+            }
+        }
+
+        Ok(quarters)
+    }
+
+    /// A unit symbol, `1`, or a parenthesized product followed by an optional exponent
+    fn term(&mut self) -> Result<Quarters, ParseUnitError> {
+        let start = self.pos;
+        let mut quarters = [0; POWER_OF_TEN + 1];
+        match self.peek() {
+            Some('(') => {
+                self.bump();
+                quarters = self.product()?;
+                self.skip_whitespace();
+                if !self.eat(')') {
+                    return Err(self.expected(Expected::ClosingParen));
+                }
+            }
+            Some('1') => {
+                self.bump();
+            }
+            Some(c) if c.is_alphabetic() => {
+                while self.peek().is_some_and(char::is_alphabetic) {
+                    self.bump();
+                }
+                let symbol = &self.input[start..self.pos];
+                if symbol != "Unitless" {
+                    let (index, power_of_ten) = lookup_symbol(symbol).ok_or_else(|| {
+                        self.error(
+                            start..self.pos,
+                            ParseUnitErrorKind::UnknownUnit(symbol.to_string()),
+                        )
+                    })?;
+                    quarters[index] = 4;
+                    quarters[POWER_OF_TEN] = 4 * power_of_ten;
+                    if power_of_ten != 0 && self.prefixed_symbol.is_none() {
+                        self.prefixed_symbol = Some(start..self.pos);
+                    }
+                }
+            }
+            _ => return Err(self.expected(Expected::Unit)),
+        }
+
+        if let Some(power) = self.exponent()? {
+            for n in quarters.iter_mut() {
+                *n = rational_to_quarters(*n as i64 * power as i64, 16).ok_or_else(|| {
+                    self.error(start..self.pos, ParseUnitErrorKind::UnrepresentableExponent)
+                })?;
+            }
+        }
+        Ok(quarters)
+    }
+
+    /// An optional exponent in either superscript (e.g. `⁻²`, `⁰⋅⁵`, `½`)
+    /// or ASCII (e.g. `^-2`, `^0.5`, `^(1/2)`) form, counted in quarters.
+    fn exponent(&mut self) -> Result<Option<i32>, ParseUnitError> {
+        let start = self.pos;
+        let result = match self.peek() {
+            Some('^') => {
+                self.bump();
+                self.ascii_exponent()?
+            }
+            Some(c)
+                if c == '⁻'
+                    || superscript_digit(c).is_some()
+                    || vulgar_fraction_quarters(c).is_some() =>
+            {
+                self.superscript_exponent()?
+            }
+            _ => return Ok(None),
+        };
+        result
+            .map(Some)
+            .ok_or_else(|| self.error(start..self.pos, ParseUnitErrorKind::UnrepresentableExponent))
+    }
+
+    fn superscript_exponent(&mut self) -> Result<Option<i32>, ParseUnitError> {
+        let negative = self.eat('⁻');
+        let (whole_digits, whole) = self.digits(superscript_digit);
+        let mut quarters = if whole_digits > 0
+            && self.peek() == Some('⋅')
+            && self.peek_second().and_then(superscript_digit).is_some()
+        {
+            self.bump();
+            let (fraction_digits, fraction) = self.digits(superscript_digit);
+            decimal_to_quarters(whole, fraction, fraction_digits)
+        } else {
+            decimal_to_quarters(whole, Some(0), 0)
+        };
+        let mut has_digits = whole_digits > 0;
+        if let Some(fraction) = self.peek().and_then(vulgar_fraction_quarters) {
+            self.bump();
+            has_digits = true;
+            quarters = quarters.and_then(|q| q.checked_add(fraction));
+        }
+        if !has_digits {
+            return Err(self.expected(Expected::Exponent));
+        }
+        Ok(quarters.map(|q| if negative { -q } else { q }))
+    }
+
+    fn ascii_exponent(&mut self) -> Result<Option<i32>, ParseUnitError> {
+        let digit = |c: char| c.to_digit(10).map(i64::from);
+        let parenthesized = self.eat('(');
+        let negative = self.eat('-');
+        let (whole_digits, whole) = self.digits(digit);
+        if whole_digits == 0 {
+            return Err(self.expected(Expected::Exponent));
+        }
+        let quarters = if self.eat('.') {
+            let (fraction_digits, fraction) = self.digits(digit);
+            if fraction_digits == 0 {
+                return Err(self.expected(Expected::Exponent));
+            }
+            decimal_to_quarters(whole, fraction, fraction_digits)
+        } else if parenthesized && self.eat('/') {
+            let (denominator_digits, denominator) = self.digits(digit);
+            if denominator_digits == 0 {
+                return Err(self.expected(Expected::Exponent));
+            }
+            whole
+                .zip(denominator)
+                .and_then(|(n, d)| rational_to_quarters(n, d))
+        } else {
+            decimal_to_quarters(whole, Some(0), 0)
+        };
+        if parenthesized && !self.eat(')') {
+            return Err(self.expected(Expected::ClosingParen));
+        }
+        Ok(quarters.map(|q| if negative { -q } else { q }))
+    }
+
+    /// Parses a run of digits returning how many there were
+    /// and their value (or `None` if the value overflowed).
+    fn digits(&mut self, digit: impl Fn(char) -> Option<i64>) -> (u32, Option<i64>) {
+        let mut count = 0;
+        let mut value = Some(0i64);
+        while let Some(d) = self.peek().and_then(&digit) {
+            self.bump();
+            count += 1;
+            value = value.and_then(|v| v.checked_mul(10)?.checked_add(d));
+        }
+        (count, value)
+    }
+}
+
+/// Looks up a symbol which may have a prefix returning its index
+/// in `SYMBOLS` and its power of ten (e.g. `km` is the meter and 3).
+fn lookup_symbol(symbol: &str) -> Option<(usize, i32)> {
+    lookup_unprefixed_symbol(symbol).or_else(|| {
+        // Prefixes are one or two characters long (e.g. `k` or `da`)
+        symbol.char_indices().skip(1).take(2).find_map(|(i, _)| {
+            let (prefix, unit) = symbol.split_at(i);
+            let prefix = Prefix::from_symbol(prefix)?;
+            // The kilogram already has a prefix
+            if unit == "kg" {
+                return None;
+            }
+            let (index, power_of_ten) = lookup_unprefixed_symbol(unit)?;
+            Some((index, power_of_ten + prefix.power_of_ten() as i32))
+        })
+    })
+}
+
+fn lookup_unprefixed_symbol(symbol: &str) -> Option<(usize, i32)> {
+    match symbol {
+        // The gram is a thousandth of the kilogram
+        "g" => Some((0, -3)),
+        // U+2126 OHM SIGN is canonically equivalent to the Greek capital omega we display
+        "\u{2126}" => lookup_unprefixed_symbol("Ω"),
+        _ => SYMBOLS.iter().position(|s| *s == symbol).map(|i| (i, 0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        base,
+        derived::{self, DerivedUnit},
+        exponents::{FractionalExponent, ue},
+    };
+
+    use super::*;
+
+    fn parse_derived(s: &str) -> DerivedUnit {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let units = [
+            derived::NEWTON,
+            derived::JOULE * derived::METER,
+            derived::KILOGRAM / (derived::METER * derived::SECOND * derived::SECOND),
+            derived::UNITLESS / derived::SECOND,
+            derived::OHM * derived::SIEMENS.pow(3),
+            derived::UNITLESS,
+            derived::RADIAN / derived::SECOND,
+            derived::STERADIAN.pow(-1),
+            derived::LUMEN * derived::SECOND,
+            DerivedUnit {
+                farad: 1,
+                sievert: -2,
+                ..derived::UNITLESS
+            },
+        ];
+        for unit in units {
+            let displayed = unit.to_string();
+            let parsed = parse_derived(&displayed);
+            assert_eq!(displayed, parsed.to_string());
+        }
+
+        let units = [
+            base::NEWTON,
+            base::PASCAL,
+            base::FARAD,
+            base::HERTZ,
+            base::UNITLESS,
+        ];
+        for unit in units {
+            let displayed = unit.to_string();
+            assert_eq!(unit, displayed.parse::<BaseUnit>().unwrap(), "{displayed}");
+        }
+    }
+
+    #[test]
+    fn test_ascii_forms() {
+        let newton = base::KILOGRAM * base::METER / (base::SECOND * base::SECOND);
+        for s in [
+            "kg*m/s^2",
+            "kg m s^-2",
+            "kg·m·s⁻²",
+            "kg m / s / s",
+            "kg*m/(s^2)",
+            "N",
+            "J/m",
+        ] {
+            assert_eq!(newton, s.parse::<BaseUnit>().unwrap(), "{s}");
+        }
+        assert_eq!(derived::HERTZ, parse_derived("1/s"));
+        assert_eq!(derived::HERTZ, parse_derived("Hz"));
+        assert_eq!(derived::OHM, parse_derived("\u{2126}"));
+        assert_eq!(derived::UNITLESS, parse_derived("Unitless"));
+    }
+
+    #[test]
+    fn test_fractional_exponents() {
+        let half_meter = BaseUnit {
+            meter: ue(1) / 2,
+            ..BaseUnit::<FractionalExponent>::unitless()
+        };
+        for s in ["m^0.5", "m^(1/2)", "m⁰⋅⁵", "m½", "(m²)^(1/4)"] {
+            assert_eq!(half_meter, s.parse().unwrap(), "{s}");
+        }
+        assert_eq!(half_meter, half_meter.to_string().parse().unwrap());
+
+        let unit = BaseUnit {
+            kilogram: ue(5) / 4,
+            second: -(ue(3) / 4),
+            ..BaseUnit::<FractionalExponent>::unitless()
+        };
+        assert_eq!(unit, unit.to_string().parse().unwrap());
+
+        // Integer exponents can't hold fractions
+        let error = "m^0.5".parse::<BaseUnit>().unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::UnrepresentableExponent);
+        let error = "m^(1/3)"
+            .parse::<BaseUnit<FractionalExponent>>()
+            .unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::UnrepresentableExponent);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = "kg⋅mx/s".parse::<BaseUnit>().unwrap_err();
+        assert_eq!(error.span, 5..7);
+        assert_eq!(error.kind, ParseUnitErrorKind::UnknownUnit("mx".into()));
+
+        let error = "".parse::<BaseUnit>().unwrap_err();
+        assert_eq!(error.span, 0..0);
+        assert_eq!(error.kind, ParseUnitErrorKind::Expected(Expected::Unit));
+
+        let error = "kg/(m⋅s".parse::<DerivedUnit>().unwrap_err();
+        assert_eq!(error.span, 9..9);
+        assert_eq!(
+            error.kind,
+            ParseUnitErrorKind::Expected(Expected::ClosingParen)
+        );
+
+        let error = "m^".parse::<DerivedUnit>().unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::Expected(Expected::Exponent));
+
+        let error = "m)".parse::<DerivedUnit>().unwrap_err();
+        assert_eq!(error.span, 1..2);
+        assert_eq!(
+            error.kind,
+            ParseUnitErrorKind::Expected(Expected::OperatorOrEnd)
+        );
+
+        let error = "m^200".parse::<BaseUnit>().unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::UnrepresentableExponent);
+
+        assert_eq!(
+            "unknown unit 'mx' at 5..7",
+            "kg⋅mx/s".parse::<BaseUnit>().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_value_round_trip() {
+        let values = [
+            DerivedValue {
+                unit: derived::METER / derived::SECOND.pow(2),
+                number: 9.81f64,
+            },
+            DerivedValue::newton(-0.5),
+            DerivedValue {
+                unit: derived::UNITLESS,
+                number: 3.0,
+            },
+        ];
+        for value in values {
+            let displayed = value.to_string();
+            let parsed: DerivedValue<f64> = displayed.parse().unwrap();
+            assert_eq!(value, parsed, "{displayed}");
+            assert_eq!(displayed, parsed.to_string());
+        }
+
+        let values = [
+            BaseValue::seconds(12u32),
+            BaseValue {
+                unit: base::UNITLESS,
+                number: 7,
+            },
+            BaseValue::newton(1),
+        ];
+        for value in values {
+            let displayed = value.to_string();
+            assert_eq!(
+                value,
+                displayed.parse::<BaseValue<u32>>().unwrap(),
+                "{displayed}"
+            );
+        }
+
+        assert_eq!(
+            DerivedValue::newton(2.5f64),
+            "2.5 kg*m/s^2".parse::<DerivedValue<f64>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_value_errors() {
+        let error = "abc m".parse::<DerivedValue<f64>>().unwrap_err();
+        assert!(matches!(error, ParseValueError::Number { span, .. } if span == (0..3)));
+
+        let error = "1.5 m/q".parse::<BaseValue<f64>>().unwrap_err();
+        assert_eq!(
+            error,
+            ParseValueError::Unit(ParseUnitError {
+                span: 6..7,
+                kind: ParseUnitErrorKind::UnknownUnit("q".into()),
+            })
+        );
+    }
+
+    /// A number type which only implements `FromStr`
+    #[derive(Debug, PartialEq)]
+    struct Digits(String);
+
+    impl FromStr for Digits {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            if s.chars().all(|c| c.is_ascii_digit()) {
+                Ok(Self(s.to_string()))
+            } else {
+                Err(s.to_string())
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_str_numbers() {
+        let value: DerivedValue<Digits> = "12 m/s".parse().unwrap();
+        assert_eq!(Digits("12".into()), value.number);
+        assert_eq!(parse_derived("m/s"), value.unit);
+        let value: BaseValue<Digits> = "7".parse().unwrap();
+        assert_eq!(base::UNITLESS, value.unit);
+
+        let error = "12 km".parse::<DerivedValue<Digits>>().unwrap_err();
+        assert_eq!(
+            error,
+            ParseValueError::Unit(ParseUnitError {
+                span: 3..5,
+                kind: ParseUnitErrorKind::Scaled("km".into()),
+            })
+        );
+        let error = "1.5 m".parse::<DerivedValue<Digits>>().unwrap_err();
+        assert!(matches!(error, ParseValueError::Number { span, .. } if span == (0..3)));
+    }
+
+    #[test]
+    fn test_prefixed_values() {
+        assert_eq!(
+            DerivedValue::newton(3000.0),
+            DerivedValue::<f64>::parse_prefixed("3 kN").unwrap()
+        );
+        assert_eq!(
+            DerivedValue::volts(0.002),
+            DerivedValue::<f64>::parse_prefixed("2 mV").unwrap()
+        );
+        assert_eq!(
+            BaseValue::seconds(2e-6),
+            BaseValue::<f64>::parse_prefixed("2 µs").unwrap()
+        );
+        assert_eq!(
+            BaseValue::seconds(2e-6),
+            BaseValue::<f64>::parse_prefixed("2 us").unwrap()
+        );
+        assert_eq!(
+            BaseValue::kilograms(0.005),
+            BaseValue::<f64>::parse_prefixed("5 g").unwrap()
+        );
+        assert_eq!(
+            BaseValue::kilograms(5e-6),
+            BaseValue::<f64>::parse_prefixed("5 mg").unwrap()
+        );
+        assert_eq!(
+            BaseValue::kilograms(5),
+            BaseValue::<i32>::parse_prefixed("5 kg").unwrap()
+        );
+        assert_eq!(
+            BaseValue::meters(30),
+            BaseValue::<i32>::parse_prefixed("3 dam").unwrap()
+        );
+        assert_eq!(
+            BaseValue {
+                unit: base::METER_SQ,
+                number: 2_000_000,
+            },
+            BaseValue::<i32>::parse_prefixed("2 km²").unwrap()
+        );
+        // Prefixes in the denominator divide
+        assert_eq!(
+            BaseValue {
+                unit: base::METER / base::SECOND,
+                number: 2000,
+            },
+            BaseValue::<i32>::parse_prefixed("2 m/ms").unwrap()
+        );
+
+        let error = DerivedValue::<i32>::parse_prefixed("3 mN").unwrap_err();
+        assert_eq!(error, ParseValueError::UnrepresentableNumber { span: 0..1 });
+
+        // FromStr doesn't apply prefixes
+        let error = "3 kN".parse::<DerivedValue<f64>>().unwrap_err();
+        assert_eq!(
+            error,
+            ParseValueError::Unit(ParseUnitError {
+                span: 2..4,
+                kind: ParseUnitErrorKind::Scaled("kN".into()),
+            })
+        );
+        let error = "5 m/g".parse::<BaseValue<f64>>().unwrap_err();
+        assert_eq!(
+            error,
+            ParseValueError::Unit(ParseUnitError {
+                span: 4..5,
+                kind: ParseUnitErrorKind::Scaled("g".into()),
+            })
+        );
+        assert_eq!(
+            DerivedValue::meters(3.0),
+            "3 km/km m".parse::<DerivedValue<f64>>().unwrap()
+        );
+        let error = "kN".parse::<DerivedUnit>().unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::Scaled("kN".into()));
+        let error = "m/kkg".parse::<DerivedUnit>().unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::UnknownUnit("kkg".into()));
+        // Prefixes that cancel out are fine
+        assert_eq!(derived::UNITLESS, parse_derived("km/km"));
+    }
+
+    #[test]
+    fn test_scaled_units() {
+        assert_eq!(scaled::HOUR, "h".parse().unwrap());
+        assert_eq!(scaled::DEGREE_CELSIUS, "°C".parse().unwrap());
+        assert_eq!(scaled::DEGREE, "°".parse().unwrap());
+        assert_eq!(scaled::REVOLUTION, "rev".parse().unwrap());
+        let unit: ScaledUnit = "mrad".parse().unwrap();
+        assert_eq!(1, unit.unit().radian);
+        assert_eq!(Factor::ratio(1, 1000), unit.factor());
+
+        let unit: ScaledUnit = "km/ms".parse().unwrap();
+        assert_eq!("km/ms", unit.symbol());
+        assert_eq!(derived::METER / derived::SECOND, unit.unit());
+        assert_eq!(Factor::integer(1000000), unit.factor());
+
+        let unit: ScaledUnit = "mg".parse().unwrap();
+        assert_eq!(
+            scaled::ScaledUnit::prefixed(Prefix::Milli, derived::KILOGRAM),
+            unit
+        );
+        let unit: ScaledUnit = "N".parse().unwrap();
+        assert_eq!(Factor::ONE, unit.factor());
+
+        let error = "km^0.5".parse::<ScaledUnit>().unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::UnrepresentableExponent);
+        let error = "h/s".parse::<ScaledUnit>().unwrap_err();
+        assert_eq!(error.kind, ParseUnitErrorKind::UnknownUnit("h".into()));
+    }
+}
@@ -0,0 +1,367 @@
+//! Points measured from an origin (e.g. timestamps from the Unix epoch)
+//! which are kept apart from the displacements between them (e.g. durations).
+//!
+//! A `Point` wraps a `BaseValue` or `DerivedValue` with the `Origin` it's measured from.
+//! Subtracting two points with the same origin gives a displacement
+//! and adding a displacement to a point gives a point, but two points can't be added.
+//!
+//! ```
+//! use physical_units::{derived::DerivedValue, point::{Point, UNIX_EPOCH}};
+//!
+//! let start = Point::new(UNIX_EPOCH, DerivedValue::seconds(1000.0));
+//! let end = (start + DerivedValue::seconds(30.0)).unwrap();
+//! assert_eq!(Ok(DerivedValue::seconds(30.0)), end - start);
+//! ```
+//!
+//! ```compile_fail
+//! use physical_units::{derived::DerivedValue, point::{Point, UNIX_EPOCH}};
+//!
+//! let start = Point::new(UNIX_EPOCH, DerivedValue::seconds(1000.0));
+//! let sum = start + start;
+//! ```
+//!
+//! Points in time with a value in seconds convert to and from `SystemTime` (from the Unix epoch)
+//! and `Instant` (from an `Instant` chosen as the epoch).
+//! The offset is an `f64`, so a present-day `SystemTime` keeps about a quarter of a microsecond of precision
+//! (the spacing of `f64` near 1.7e9 s) and the nanoseconds may not survive a round trip.
+
+use core::{cmp::Ordering, fmt};
+use std::{
+    ops::{Add, Sub},
+    time::{Duration, Instant, SystemTime},
+};
+
+use thiserror::Error;
+
+use crate::{
+    derived::{self, DerivedValue, UnitMismatch},
+    temperature::Temperature,
+};
+
+/// What a `Point` is measured from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// A fixed origin identified by its name (e.g. the Unix epoch)
+    Named(&'static str),
+    /// A moment on the monotonic clock, which can't be compared across processes
+    Instant(Instant),
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Named(name) => write!(f, "{name}"),
+            Origin::Instant(instant) => write!(f, "{instant:?}"),
+        }
+    }
+}
+
+/// 1970-01-01 00:00:00 UTC, see `SystemTime::UNIX_EPOCH`
+pub const UNIX_EPOCH: Origin = Origin::Named("Unix epoch");
+
+/// 0 K, the origin of `Temperature`
+pub const ABSOLUTE_ZERO: Origin = Origin::Named("absolute zero");
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum PointError<UnitError> {
+    #[error("Point measured from '{lhs}' can't be combined with one measured from '{rhs}'")]
+    Origin { lhs: Origin, rhs: Origin },
+    #[error("Point measured from '{0}' isn't measured from an instant")]
+    NotInstant(Origin),
+    #[error(transparent)]
+    Unit(UnitError),
+    #[error("Point is out of the range of the clock")]
+    OutOfRange,
+}
+
+/// A value (e.g. `DerivedValue`) measured from an origin.
+#[derive(Debug, Clone, Copy)]
+pub struct Point<Value> {
+    origin: Origin,
+    offset: Value,
+}
+
+impl<Value> Point<Value> {
+    pub const fn new(origin: Origin, offset: Value) -> Self {
+        Self { origin, offset }
+    }
+
+    pub const fn origin(&self) -> Origin {
+        self.origin
+    }
+
+    /// The displacement from the origin to the point.
+    pub const fn offset(&self) -> &Value {
+        &self.offset
+    }
+
+    pub fn into_offset(self) -> Value {
+        self.offset
+    }
+}
+
+/// Points are only equal if their origins are.
+impl<Value: PartialEq> PartialEq for Point<Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin && self.offset == other.offset
+    }
+}
+
+/// Points are only ordered when their origins match.
+impl<Value: PartialOrd> PartialOrd for Point<Value> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.origin != other.origin {
+            return None;
+        }
+        self.offset.partial_cmp(&other.offset)
+    }
+}
+
+impl<Value: fmt::Display> fmt::Display for Point<Value> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} from {}", self.offset, self.origin)
+    }
+}
+
+/// The displacement between two points, failing if they have different origins or units.
+impl<Value, UnitError> Sub for Point<Value>
+where
+    Value: Sub<Output = Result<Value, UnitError>>,
+{
+    type Output = Result<Value, PointError<UnitError>>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.origin != rhs.origin {
+            return Err(PointError::Origin {
+                lhs: self.origin,
+                rhs: rhs.origin,
+            });
+        }
+        (self.offset - rhs.offset).map_err(PointError::Unit)
+    }
+}
+
+/// Moves the point by a displacement, failing if the units don't match.
+impl<Value, UnitError> Add<Value> for Point<Value>
+where
+    Value: Add<Output = Result<Value, UnitError>>,
+{
+    type Output = Result<Self, UnitError>;
+
+    fn add(self, rhs: Value) -> Self::Output {
+        Ok(Self {
+            origin: self.origin,
+            offset: (self.offset + rhs)?,
+        })
+    }
+}
+
+/// Moves the point back by a displacement, failing if the units don't match.
+impl<Value, UnitError> Sub<Value> for Point<Value>
+where
+    Value: Sub<Output = Result<Value, UnitError>>,
+{
+    type Output = Result<Self, UnitError>;
+
+    fn sub(self, rhs: Value) -> Self::Output {
+        Ok(Self {
+            origin: self.origin,
+            offset: (self.offset - rhs)?,
+        })
+    }
+}
+
+impl<Number> From<Temperature<Number>> for Point<DerivedValue<Number>> {
+    fn from(value: Temperature<Number>) -> Self {
+        Self::new(ABSOLUTE_ZERO, value.into_value())
+    }
+}
+
+/// A duration in seconds.
+impl From<Duration> for DerivedValue<f64> {
+    fn from(value: Duration) -> Self {
+        DerivedValue::seconds(value.as_secs_f64())
+    }
+}
+
+/// The signed number of seconds between `time` and `epoch`.
+fn seconds_since<Time: Copy>(
+    time: Time,
+    epoch: Time,
+    since: impl Fn(Time, Time) -> Option<Duration>,
+) -> f64 {
+    match since(time, epoch) {
+        Some(duration) => duration.as_secs_f64(),
+        None => -since(epoch, time).unwrap_or_default().as_secs_f64(),
+    }
+}
+
+impl Point<DerivedValue<f64>> {
+    /// The time in seconds from the Unix epoch,
+    /// rounded to the nearest `f64` (about 240 ns apart for present-day times).
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let seconds = seconds_since(time, SystemTime::UNIX_EPOCH, |time, epoch| {
+            time.duration_since(epoch).ok()
+        });
+        Self::new(UNIX_EPOCH, DerivedValue::seconds(seconds))
+    }
+
+    /// The time in seconds from `epoch`.
+    pub fn from_instant(instant: Instant, epoch: Instant) -> Self {
+        let seconds = seconds_since(instant, epoch, |instant, epoch| {
+            instant.checked_duration_since(epoch)
+        });
+        Self::new(Origin::Instant(epoch), DerivedValue::seconds(seconds))
+    }
+
+    /// Fails unless the point is in seconds from the Unix epoch and within the range of `SystemTime`.
+    /// The offset is rounded to the nearest nanosecond, so it only gives back the `SystemTime`
+    /// a point was made from when that time is representable as an `f64` number of seconds.
+    pub fn to_system_time(&self) -> Result<SystemTime, PointError<UnitMismatch<i8>>> {
+        self.check_origin(UNIX_EPOCH)?;
+        self.offset_epoch(
+            SystemTime::UNIX_EPOCH,
+            SystemTime::checked_add,
+            SystemTime::checked_sub,
+        )
+    }
+
+    /// Fails unless the point is in seconds from an `Instant` and within the range of `Instant`.
+    pub fn to_instant(&self) -> Result<Instant, PointError<UnitMismatch<i8>>> {
+        let Origin::Instant(epoch) = self.origin else {
+            return Err(PointError::NotInstant(self.origin));
+        };
+        self.offset_epoch(epoch, Instant::checked_add, Instant::checked_sub)
+    }
+
+    fn check_origin(&self, origin: Origin) -> Result<(), PointError<UnitMismatch<i8>>> {
+        if self.origin != origin {
+            return Err(PointError::Origin {
+                lhs: self.origin,
+                rhs: origin,
+            });
+        }
+        Ok(())
+    }
+
+    /// Moves `epoch` by the offset in seconds.
+    fn offset_epoch<Time>(
+        &self,
+        epoch: Time,
+        add: impl Fn(&Time, Duration) -> Option<Time>,
+        sub: impl Fn(&Time, Duration) -> Option<Time>,
+    ) -> Result<Time, PointError<UnitMismatch<i8>>> {
+        if self.offset.unit != derived::SECOND {
+            return Err(PointError::Unit(UnitMismatch {
+                lhs: self.offset.unit,
+                rhs: derived::SECOND,
+            }));
+        }
+        let seconds = self.offset.number;
+        let duration =
+            Duration::try_from_secs_f64(seconds.abs()).map_err(|_| PointError::OutOfRange)?;
+        let time = if seconds < 0.0 {
+            sub(&epoch, duration)
+        } else {
+            add(&epoch, duration)
+        };
+        time.ok_or(PointError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base::{self, BaseValue};
+
+    #[test]
+    fn test_affine_arithmetic() {
+        let here = Point::new(Origin::Named("survey marker"), BaseValue::meters(3));
+        let there = (here + BaseValue::meters(4)).unwrap();
+        assert_eq!(Ok(BaseValue::meters(4)), there - here);
+        assert_eq!(Ok(here), there - BaseValue::meters(4));
+        assert!(here < there);
+        assert_eq!("7 m from survey marker", there.to_string());
+
+        let error = (here + BaseValue::seconds(1)).unwrap_err();
+        assert_eq!(base::SECOND, error.rhs);
+
+        let elsewhere = Point::new(UNIX_EPOCH, BaseValue::meters(3));
+        assert_eq!(None, here.partial_cmp(&elsewhere));
+        assert_eq!(
+            "Point measured from 'survey marker' can't be combined with one measured from 'Unix epoch'",
+            (here - elsewhere).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_system_time() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(86400);
+        let point = Point::from_system_time(time);
+        assert_eq!(
+            Point::new(UNIX_EPOCH, DerivedValue::seconds(86400.0)),
+            point
+        );
+        assert_eq!(Ok(time), point.to_system_time());
+
+        let before = SystemTime::UNIX_EPOCH - Duration::from_secs(60);
+        let point = Point::from_system_time(before);
+        assert_eq!(-60.0, point.offset().number);
+        assert_eq!(Ok(before), point.to_system_time());
+
+        // Sub-second times round trip while they fit in an f64
+        let time = SystemTime::UNIX_EPOCH + Duration::from_millis(86_400_250);
+        let point = Point::from_system_time(time);
+        assert_eq!(86400.25, point.offset().number);
+        assert_eq!(Ok(time), point.to_system_time());
+        let before = SystemTime::UNIX_EPOCH - Duration::from_millis(60_500);
+        assert_eq!(Ok(before), Point::from_system_time(before).to_system_time());
+
+        // Present-day nanoseconds are rounded to the nearest f64
+        let now = SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789);
+        let rounded = Point::from_system_time(now).to_system_time().unwrap();
+        let error = rounded
+            .duration_since(now)
+            .or_else(|_| now.duration_since(rounded))
+            .unwrap();
+        assert!(error <= Duration::from_nanos(120), "{error:?}");
+
+        let day = (Point::from_system_time(time) - Point::from_system_time(before)).unwrap();
+        assert_eq!(DerivedValue::from(Duration::from_millis(86_460_750)), day);
+
+        let meters = Point::new(UNIX_EPOCH, DerivedValue::meters(1.0));
+        assert!(matches!(meters.to_system_time(), Err(PointError::Unit(_))));
+        let huge = Point::new(UNIX_EPOCH, DerivedValue::seconds(f64::MAX));
+        assert_eq!(Err(PointError::OutOfRange), huge.to_system_time());
+    }
+
+    #[test]
+    fn test_instant() {
+        let epoch = Instant::now();
+        let later = epoch + Duration::from_millis(1500);
+        let point = Point::from_instant(later, epoch);
+        assert_eq!(1.5, point.offset().number);
+        assert_eq!(Ok(later), point.to_instant());
+        assert_eq!(-1.5, Point::from_instant(epoch, later).offset().number);
+
+        let error = Point::from_system_time(SystemTime::UNIX_EPOCH)
+            .to_instant()
+            .unwrap_err();
+        assert_eq!(PointError::NotInstant(UNIX_EPOCH), error);
+        let error = point.to_system_time().unwrap_err();
+        assert!(matches!(
+            error,
+            PointError::Origin {
+                rhs: UNIX_EPOCH,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_temperature() {
+        let point = Point::from(Temperature::from_kelvin(300.0));
+        assert_eq!(ABSOLUTE_ZERO, point.origin());
+        assert_eq!(DerivedValue::kelvin(300.0), *point.offset());
+    }
+}
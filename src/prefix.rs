@@ -0,0 +1,318 @@
+//! SI prefixes (e.g. kilo, milli) which scale a unit by a power of ten.
+//!
+//! Following SI conventions, a prefix attaches to the first symbol of a unit
+//! and is raised to that symbol's exponent along with it
+//! (e.g. 1 km² is 10⁶ m² and 1 mN⋅m is 10⁻³ N⋅m).
+//! Since the kilogram is already prefixed, prefixes on mass apply to the gram
+//! (e.g. 1 mg is 10⁻⁶ kg).
+
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Prefix {
+    /// quecto (q) 10⁻³⁰
+    Quecto,
+    /// ronto (r) 10⁻²⁷
+    Ronto,
+    /// yocto (y) 10⁻²⁴
+    Yocto,
+    /// zepto (z) 10⁻²¹
+    Zepto,
+    /// atto (a) 10⁻¹⁸
+    Atto,
+    /// femto (f) 10⁻¹⁵
+    Femto,
+    /// pico (p) 10⁻¹²
+    Pico,
+    /// nano (n) 10⁻⁹
+    Nano,
+    /// micro (µ) 10⁻⁶
+    Micro,
+    /// milli (m) 10⁻³
+    Milli,
+    /// centi (c) 10⁻²
+    Centi,
+    /// deci (d) 10⁻¹
+    Deci,
+    /// deca (da) 10¹
+    Deca,
+    /// hecto (h) 10²
+    Hecto,
+    /// kilo (k) 10³
+    Kilo,
+    /// mega (M) 10⁶
+    Mega,
+    /// giga (G) 10⁹
+    Giga,
+    /// tera (T) 10¹²
+    Tera,
+    /// peta (P) 10¹⁵
+    Peta,
+    /// exa (E) 10¹⁸
+    Exa,
+    /// zetta (Z) 10²¹
+    Zetta,
+    /// yotta (Y) 10²⁴
+    Yotta,
+    /// ronna (R) 10²⁷
+    Ronna,
+    /// quetta (Q) 10³⁰
+    Quetta,
+}
+
+impl Prefix {
+    pub const ALL: [Prefix; 24] = [
+        Prefix::Quecto,
+        Prefix::Ronto,
+        Prefix::Yocto,
+        Prefix::Zepto,
+        Prefix::Atto,
+        Prefix::Femto,
+        Prefix::Pico,
+        Prefix::Nano,
+        Prefix::Micro,
+        Prefix::Milli,
+        Prefix::Centi,
+        Prefix::Deci,
+        Prefix::Deca,
+        Prefix::Hecto,
+        Prefix::Kilo,
+        Prefix::Mega,
+        Prefix::Giga,
+        Prefix::Tera,
+        Prefix::Peta,
+        Prefix::Exa,
+        Prefix::Zetta,
+        Prefix::Yotta,
+        Prefix::Ronna,
+        Prefix::Quetta,
+    ];
+
+    /// The power of ten this prefix multiplies by (e.g. 3 for kilo)
+    pub const fn power_of_ten(self) -> i8 {
+        match self {
+            Prefix::Quecto => -30,
+            Prefix::Ronto => -27,
+            Prefix::Yocto => -24,
+            Prefix::Zepto => -21,
+            Prefix::Atto => -18,
+            Prefix::Femto => -15,
+            Prefix::Pico => -12,
+            Prefix::Nano => -9,
+            Prefix::Micro => -6,
+            Prefix::Milli => -3,
+            Prefix::Centi => -2,
+            Prefix::Deci => -1,
+            Prefix::Deca => 1,
+            Prefix::Hecto => 2,
+            Prefix::Kilo => 3,
+            Prefix::Mega => 6,
+            Prefix::Giga => 9,
+            Prefix::Tera => 12,
+            Prefix::Peta => 15,
+            Prefix::Exa => 18,
+            Prefix::Zetta => 21,
+            Prefix::Yotta => 24,
+            Prefix::Ronna => 27,
+            Prefix::Quetta => 30,
+        }
+    }
+
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Prefix::Quecto => "q",
+            Prefix::Ronto => "r",
+            Prefix::Yocto => "y",
+            Prefix::Zepto => "z",
+            Prefix::Atto => "a",
+            Prefix::Femto => "f",
+            Prefix::Pico => "p",
+            Prefix::Nano => "n",
+            Prefix::Micro => "µ",
+            Prefix::Milli => "m",
+            Prefix::Centi => "c",
+            Prefix::Deci => "d",
+            Prefix::Deca => "da",
+            Prefix::Hecto => "h",
+            Prefix::Kilo => "k",
+            Prefix::Mega => "M",
+            Prefix::Giga => "G",
+            Prefix::Tera => "T",
+            Prefix::Peta => "P",
+            Prefix::Exa => "E",
+            Prefix::Zetta => "Z",
+            Prefix::Yotta => "Y",
+            Prefix::Ronna => "R",
+            Prefix::Quetta => "Q",
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Prefix::Quecto => "quecto",
+            Prefix::Ronto => "ronto",
+            Prefix::Yocto => "yocto",
+            Prefix::Zepto => "zepto",
+            Prefix::Atto => "atto",
+            Prefix::Femto => "femto",
+            Prefix::Pico => "pico",
+            Prefix::Nano => "nano",
+            Prefix::Micro => "micro",
+            Prefix::Milli => "milli",
+            Prefix::Centi => "centi",
+            Prefix::Deci => "deci",
+            Prefix::Deca => "deca",
+            Prefix::Hecto => "hecto",
+            Prefix::Kilo => "kilo",
+            Prefix::Mega => "mega",
+            Prefix::Giga => "giga",
+            Prefix::Tera => "tera",
+            Prefix::Peta => "peta",
+            Prefix::Exa => "exa",
+            Prefix::Zetta => "zetta",
+            Prefix::Yotta => "yotta",
+            Prefix::Ronna => "ronna",
+            Prefix::Quetta => "quetta",
+        }
+    }
+
+    pub fn from_power_of_ten(power: i8) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|prefix| prefix.power_of_ten() == power)
+    }
+
+    /// Also accepts the Greek small letter mu (μ) and `u` for micro.
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        match symbol {
+            "μ" | "u" => Some(Prefix::Micro),
+            _ => Self::ALL
+                .into_iter()
+                .find(|prefix| prefix.symbol() == symbol),
+        }
+    }
+}
+
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// Displays a value with a prefix on the first symbol of its unit (e.g. `3 kN`),
+/// created by `display_with_prefix` on `BaseValue` and `DerivedValue`.
+///
+/// Falls back to displaying the value without a prefix if the unit is unitless
+/// or the number can't be represented after scaling.
+pub struct PrefixedValue<'a, Number, Unit> {
+    pub(crate) number: &'a Number,
+    pub(crate) unit: &'a Unit,
+    pub(crate) prefix: Prefix,
+}
+
+/// A number which can be multiplied by a power of ten to apply a prefix.
+pub trait PowerOfTen: Sized {
+    /// Computes `self * 10^power` returning `None` if it can't be
+    /// represented exactly (e.g. `3 * 10⁻³` as an integer).
+    fn checked_mul_power_of_ten(self, power: i32) -> Option<Self>;
+}
+
+macro_rules! impl_power_of_ten_float {
+    ($type:ident) => {
+        impl PowerOfTen for $type {
+            fn checked_mul_power_of_ten(self, power: i32) -> Option<Self> {
+                // Dividing by an exact power of ten rounds better
+                // than multiplying by an inexact negative power of ten
+                if power >= 0 {
+                    Some(self * (10 as $type).powi(power))
+                } else {
+                    Some(self / (10 as $type).powi(-power))
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_power_of_ten_int {
+    ($type:ident) => {
+        impl PowerOfTen for $type {
+            fn checked_mul_power_of_ten(self, power: i32) -> Option<Self> {
+                let factor = (10 as $type).checked_pow(power.unsigned_abs());
+                if power >= 0 {
+                    self.checked_mul(factor?)
+                } else {
+                    match factor {
+                        Some(factor) if self % factor == 0 => Some(self / factor),
+                        None if self == 0 => Some(0),
+                        _ => None,
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_power_of_ten_float!(f32);
+impl_power_of_ten_float!(f64);
+
+impl_power_of_ten_int!(i8);
+impl_power_of_ten_int!(i16);
+impl_power_of_ten_int!(i32);
+impl_power_of_ten_int!(i64);
+impl_power_of_ten_int!(i128);
+
+impl_power_of_ten_int!(u8);
+impl_power_of_ten_int!(u16);
+impl_power_of_ten_int!(u32);
+impl_power_of_ten_int!(u64);
+impl_power_of_ten_int!(u128);
+
+/// Finds the symbol a prefix attaches to (the first symbol displayed)
+/// returning its index and exponent.
+pub(crate) fn leading_component(exponents: &[i8]) -> Option<(usize, i8)> {
+    exponents
+        .iter()
+        .position(|n| *n > 0)
+        .or_else(|| exponents.iter().position(|n| *n < 0))
+        .map(|i| (i, exponents[i]))
+}
+
+/// The power of ten a prefix scales a unit by when it attaches to the leading
+/// symbol, where index 0 is the kilogram and the prefix applies to the gram.
+pub(crate) fn prefix_power_of_ten(prefix: Prefix, exponents: &[i8]) -> i32 {
+    match leading_component(exponents) {
+        Some((0, n)) => (prefix.power_of_ten() as i32 - 3) * n as i32,
+        Some((_, n)) => prefix.power_of_ten() as i32 * n as i32,
+        None => prefix.power_of_ten() as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_lookup() {
+        for prefix in Prefix::ALL {
+            assert_eq!(Some(prefix), Prefix::from_symbol(prefix.symbol()));
+            assert_eq!(
+                Some(prefix),
+                Prefix::from_power_of_ten(prefix.power_of_ten())
+            );
+        }
+        assert_eq!(Some(Prefix::Micro), Prefix::from_symbol("u"));
+        assert_eq!(Some(Prefix::Micro), Prefix::from_symbol("μ"));
+        assert_eq!(None, Prefix::from_power_of_ten(4));
+    }
+
+    #[test]
+    fn test_power_of_ten() {
+        assert_eq!(Some(3000), 3.checked_mul_power_of_ten(3));
+        assert_eq!(Some(3), 3000u32.checked_mul_power_of_ten(-3));
+        assert_eq!(None, 3i32.checked_mul_power_of_ten(-3));
+        assert_eq!(None, 3u8.checked_mul_power_of_ten(3));
+        assert_eq!(Some(0), 0i8.checked_mul_power_of_ten(-30));
+        assert_eq!(Some(0.003), 3f64.checked_mul_power_of_ten(-3));
+        assert_eq!(Some(2.5e9), 2.5f64.checked_mul_power_of_ten(9));
+    }
+}
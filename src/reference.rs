@@ -0,0 +1,288 @@
+//! Nondimensionalization against reference scales (e.g. a reference length, velocity, and density).
+//!
+//! `ReferenceScales` solves for the product of powers of the reference values
+//! which has the same dimension as a value (e.g. ρU² for a pressure)
+//! and divides by it to give a dimensionless number, or multiplies by it to rebuild the value.
+//!
+//! ```
+//! use physical_units::{derived::{self, DerivedValue}, reference::ReferenceScales};
+//!
+//! let velocity = DerivedValue::meters(10.0) / DerivedValue::seconds(1.0);
+//! let scales = ReferenceScales::new([DerivedValue::meters(2.0), velocity]).unwrap();
+//!
+//! let time = scales.nondimensionalize(&DerivedValue::seconds(0.4)).unwrap();
+//! assert!((time - 2.0).abs() < 1e-12);
+//! assert_eq!(DerivedValue::seconds(0.2), scales.dimensionalize(1.0, derived::SECOND).unwrap());
+//! ```
+
+use thiserror::Error;
+
+use crate::{
+    base::BaseUnit,
+    derived::{DerivedUnit, DerivedValue},
+    exponents::UnitError,
+    scaled::Factor,
+};
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ReferenceError {
+    #[error("Reference scale in '{0}' is a product of powers of the other reference scales")]
+    Dependent(DerivedUnit),
+    #[error("Reference scale in '{0}' must be positive")]
+    NonPositive(DerivedUnit),
+    #[error("Unit '{0}' isn't a product of powers of the reference scales")]
+    NotSpanned(DerivedUnit),
+    #[error(transparent)]
+    Unit(#[from] UnitError),
+}
+
+/// Reference values whose dimensions are independent,
+/// used to turn values with any dimension they span into dimensionless numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceScales {
+    basis: Vec<DerivedValue<f64>>,
+}
+
+impl ReferenceScales {
+    /// Fails if a reference value isn't positive
+    /// or its dimension is a product of powers of the others (e.g. both m and m²).
+    pub fn new(basis: impl IntoIterator<Item = DerivedValue<f64>>) -> Result<Self, ReferenceError> {
+        let basis: Vec<_> = basis.into_iter().collect();
+        let dimensions: Vec<_> = basis.iter().map(|value| value.unit.to_base()).collect();
+        for (i, value) in basis.iter().enumerate() {
+            if value.number.is_nan() || value.number <= 0.0 {
+                return Err(ReferenceError::NonPositive(value.unit));
+            }
+            if solve(&dimensions[..i], &dimensions[i])?.is_some() {
+                return Err(ReferenceError::Dependent(value.unit));
+            }
+        }
+        Ok(Self { basis })
+    }
+
+    pub fn basis(&self) -> &[DerivedValue<f64>] {
+        &self.basis
+    }
+
+    /// The power of each reference value in the product with the dimension of `unit`
+    /// (e.g. 0, 2, and 1 for a pressure with a reference length, velocity, and density).
+    pub fn powers(&self, unit: DerivedUnit) -> Result<Vec<Factor>, ReferenceError> {
+        let dimensions: Vec<_> = self
+            .basis
+            .iter()
+            .map(|value| value.unit.to_base())
+            .collect();
+        solve(&dimensions, &unit.to_base())?.ok_or(ReferenceError::NotSpanned(unit))
+    }
+
+    /// The product of powers of the reference values with the dimension of `unit`, in `unit`.
+    pub fn scale(&self, unit: DerivedUnit) -> Result<DerivedValue<f64>, ReferenceError> {
+        let number = self
+            .basis
+            .iter()
+            .zip(self.powers(unit)?)
+            .map(|(value, power)| value.number.powf(power.to_f64()))
+            .product();
+        Ok(DerivedValue::new(number, unit))
+    }
+
+    /// The value divided by the reference scale for its dimension.
+    pub fn nondimensionalize(&self, value: &DerivedValue<f64>) -> Result<f64, ReferenceError> {
+        Ok(value.number / self.scale(value.unit)?.number)
+    }
+
+    /// The inverse of `nondimensionalize`, multiplying `number` by the reference scale for `unit`.
+    pub fn dimensionalize(
+        &self,
+        number: f64,
+        unit: DerivedUnit,
+    ) -> Result<DerivedValue<f64>, ReferenceError> {
+        Ok(DerivedValue::new(number * self.scale(unit)?.number, unit))
+    }
+}
+
+fn exponents(unit: &BaseUnit) -> [i8; 7] {
+    [
+        unit.kilogram,
+        unit.meter,
+        unit.second,
+        unit.mole,
+        unit.ampere,
+        unit.kelvin,
+        unit.candela,
+    ]
+}
+
+/// `row - multiple * pivot` for each element of the rows
+fn subtract_multiple(row: &[Factor], pivot: &[Factor], multiple: Factor) -> Option<Vec<Factor>> {
+    let negated = multiple.checked_mul(Factor::integer(-1))?;
+    row.iter()
+        .zip(pivot)
+        .map(|(x, p)| x.checked_add(p.checked_mul(negated)?))
+        .collect()
+}
+
+/// Solves for the powers of `columns` whose product is `target` by Gauss–Jordan elimination,
+/// returning `None` if there aren't any. The columns must be independent.
+fn solve(columns: &[BaseUnit], target: &BaseUnit) -> Result<Option<Vec<Factor>>, UnitError> {
+    let columns: Vec<_> = columns.iter().chain([target]).map(exponents).collect();
+    let width = columns.len() - 1;
+    // One row per base unit, with the target in the last column
+    let mut rows: Vec<Vec<Factor>> = (0..7)
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| Factor::integer(column[row].into()))
+                .collect()
+        })
+        .collect();
+
+    for column in 0..width {
+        let pivot = (column..rows.len())
+            .find(|&row| !rows[row][column].is_zero())
+            .expect("reference scales should be independent");
+        rows.swap(column, pivot);
+        let inverse = rows[column][column]
+            .checked_recip()
+            .ok_or(UnitError::Overflow)?;
+        rows[column] = rows[column]
+            .iter()
+            .map(|x| x.checked_mul(inverse))
+            .collect::<Option<_>>()
+            .ok_or(UnitError::Overflow)?;
+        for row in 0..rows.len() {
+            let multiple = rows[row][column];
+            if row != column && !multiple.is_zero() {
+                rows[row] = subtract_multiple(&rows[row], &rows[column], multiple)
+                    .ok_or(UnitError::Overflow)?;
+            }
+        }
+    }
+
+    if rows[width..].iter().any(|row| !row[width].is_zero()) {
+        return Ok(None);
+    }
+    Ok(Some(rows[..width].iter().map(|row| row[width]).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base::{self, BaseUnit},
+        derived,
+    };
+
+    fn unit(base: BaseUnit) -> DerivedUnit {
+        DerivedUnit {
+            base,
+            ..derived::UNITLESS
+        }
+    }
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() <= 1e-12 * expected.abs(),
+            "{expected} != {actual}"
+        );
+    }
+
+    /// A length of 2 m, velocity of 10 m/s, density of 1.2 kg/m³, and temperature of 300 K
+    fn flow() -> ReferenceScales {
+        let velocity = unit(BaseUnit {
+            meter: 1,
+            second: -1,
+            ..base::UNITLESS
+        });
+        let density = unit(BaseUnit {
+            kilogram: 1,
+            meter: -3,
+            ..base::UNITLESS
+        });
+        ReferenceScales::new([
+            DerivedValue::meters(2.0),
+            DerivedValue::new(10.0, velocity),
+            DerivedValue::new(1.2, density),
+            DerivedValue::kelvin(300.0),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_nondimensionalize() {
+        let scales = flow();
+        let powers = scales.powers(derived::PASCAL).unwrap();
+        assert_eq!(
+            vec![Factor::ZERO, Factor::integer(2), Factor::ONE, Factor::ZERO],
+            powers
+        );
+        // ρU² = 120 Pa
+        assert_close(
+            1.5,
+            scales
+                .nondimensionalize(&DerivedValue::new(180.0, derived::PASCAL))
+                .unwrap(),
+        );
+        // L/U = 0.2 s
+        assert_close(
+            2.0,
+            scales
+                .nondimensionalize(&DerivedValue::seconds(0.4))
+                .unwrap(),
+        );
+        // ρUL = 24 Pa⋅s
+        let viscosity = derived::PASCAL * derived::SECOND;
+        assert_close(24.0, scales.scale(viscosity).unwrap().number);
+        assert_close(
+            1.0,
+            scales
+                .nondimensionalize(&DerivedValue::kelvin(300.0))
+                .unwrap(),
+        );
+
+        let rebuilt = scales.dimensionalize(3.0, derived::WATT).unwrap();
+        assert_eq!(derived::WATT, rebuilt.unit);
+        // ρU³L² = 4800 W
+        assert_close(14400.0, rebuilt.number);
+        assert_close(3.0, scales.nondimensionalize(&rebuilt).unwrap());
+    }
+
+    #[test]
+    fn test_fractional_powers() {
+        let scales = ReferenceScales::new([DerivedValue::new(4.0, derived::METER_SQ)]).unwrap();
+        assert_eq!(
+            vec![Factor::ratio(1, 2)],
+            scales.powers(derived::METER).unwrap()
+        );
+        assert_close(
+            2.0,
+            scales
+                .nondimensionalize(&DerivedValue::meters(4.0))
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = flow()
+            .nondimensionalize(&DerivedValue::amperes(1.0))
+            .unwrap_err();
+        assert_eq!(ReferenceError::NotSpanned(derived::AMPERE), error);
+        assert_eq!(
+            "Unit 'A' isn't a product of powers of the reference scales",
+            error.to_string()
+        );
+
+        let error = ReferenceScales::new([
+            DerivedValue::meters(1.0),
+            DerivedValue::new(2.0, derived::METER_SQ),
+        ])
+        .unwrap_err();
+        assert_eq!(ReferenceError::Dependent(derived::METER_SQ), error);
+        let error = ReferenceScales::new([DerivedValue::new(2.0, derived::UNITLESS)]).unwrap_err();
+        assert_eq!(ReferenceError::Dependent(derived::UNITLESS), error);
+
+        let error = ReferenceScales::new([DerivedValue::meters(-1.0)]).unwrap_err();
+        assert_eq!("Reference scale in 'm' must be positive", error.to_string());
+    }
+}
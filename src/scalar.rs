@@ -0,0 +1,108 @@
+//! Multiplying and dividing values by plain numbers (e.g. `2.0 * force`).
+//!
+//! Dividing a number by a value inverts its unit (e.g. `1.0 / 2 s` is `0.5 /s`).
+
+use std::ops::{Div, Mul};
+
+use crate::{
+    base::{BaseUnit, BaseValue},
+    derived::{DerivedUnit, DerivedValue},
+    exponents::UnitExponent,
+};
+
+macro_rules! impl_scalar {
+    ($value:ident, $unit:ident, $type:ident) => {
+        impl<ExponentType> Mul<$type> for $value<$type, ExponentType> {
+            type Output = Self;
+
+            fn mul(self, rhs: $type) -> Self::Output {
+                Self {
+                    unit: self.unit,
+                    number: self.number * rhs,
+                }
+            }
+        }
+
+        impl<ExponentType> Mul<$value<$type, ExponentType>> for $type {
+            type Output = $value<$type, ExponentType>;
+
+            fn mul(self, rhs: $value<$type, ExponentType>) -> Self::Output {
+                $value {
+                    unit: rhs.unit,
+                    number: self * rhs.number,
+                }
+            }
+        }
+
+        impl<ExponentType> Div<$type> for $value<$type, ExponentType> {
+            type Output = Self;
+
+            fn div(self, rhs: $type) -> Self::Output {
+                Self {
+                    unit: self.unit,
+                    number: self.number / rhs,
+                }
+            }
+        }
+
+        impl<ExponentType> Div<$value<$type, ExponentType>> for $type
+        where
+            ExponentType: UnitExponent,
+        {
+            type Output = $value<$type, ExponentType>;
+
+            fn div(self, rhs: $value<$type, ExponentType>) -> Self::Output {
+                $value {
+                    unit: $unit::unitless() / rhs.unit,
+                    number: self / rhs.number,
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_scalars {
+    ($($type:ident),*) => {
+        $(
+            impl_scalar!(BaseValue, BaseUnit, $type);
+            impl_scalar!(DerivedValue, DerivedUnit, $type);
+        )*
+    };
+}
+
+impl_scalars!(f32, f64);
+impl_scalars!(i8, i16, i32, i64, i128);
+impl_scalars!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{base, derived};
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let force = DerivedValue::newton(3.0);
+        assert_eq!(DerivedValue::newton(6.0), 2.0 * force);
+        assert_eq!(DerivedValue::newton(6.0), force * 2.0);
+        assert_eq!(DerivedValue::newton(1.5), force / 2.0);
+
+        let length = BaseValue::meters(6u32);
+        assert_eq!(BaseValue::meters(18), 3 * length);
+        assert_eq!(BaseValue::meters(2), length / 3);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let frequency = 1.0 / DerivedValue::seconds(2.0f64);
+        assert_eq!(DerivedValue::hertz(0.5), frequency);
+
+        let conductance = 10i64 / DerivedValue::ohms(5);
+        assert_eq!(DerivedValue::new(2, derived::SIEMENS), conductance);
+
+        let per_area = 1f32 / BaseValue::new(4.0, base::METER_SQ);
+        assert_eq!(
+            BaseValue::new(0.25, base::UNITLESS / base::METER_SQ),
+            per_area
+        );
+    }
+}
@@ -0,0 +1,658 @@
+//! Units that are a multiple of an SI unit (e.g. the hour is 3600 s),
+//! optionally with an offset for units like degrees Celsius.
+//!
+//! `BaseValue` and `DerivedValue` always store their numbers in SI units,
+//! a `ScaledUnit` describes how to convert those numbers to and from another unit.
+
+use core::fmt;
+use std::borrow::Cow;
+use std::ops::{Add, Sub};
+
+use thiserror::Error;
+
+use crate::{
+    base::BaseValue,
+    cgs, customary,
+    derived::{self, DerivedUnit, DerivedValue},
+    exponents::UnitError,
+    prefix::{Prefix, prefix_power_of_ten},
+};
+
+/// An exact conversion factor equal to `numerator / denominator * π^pi_power`.
+///
+/// Powers of π are tracked separately so that angle units (e.g. 1° is π/180 rad)
+/// are exact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Factor {
+    numerator: i128,
+    /// Always positive
+    denominator: i128,
+    pi_power: i8,
+}
+
+const fn gcd(a: i128, b: i128) -> i128 {
+    let mut a = a.abs();
+    let mut b = b.abs();
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+impl Factor {
+    pub const ZERO: Self = Self::integer(0);
+    pub const ONE: Self = Self::integer(1);
+
+    pub const fn integer(n: i128) -> Self {
+        Self {
+            numerator: n,
+            denominator: 1,
+            pi_power: 0,
+        }
+    }
+
+    /// Panics if the denominator is zero.
+    pub const fn ratio(numerator: i128, denominator: i128) -> Self {
+        if denominator == 0 {
+            panic!("Factor denominator must not be zero.")
+        }
+        let divisor = gcd(numerator, denominator);
+        let sign = if denominator < 0 { -1 } else { 1 };
+        Self {
+            numerator: sign * numerator / divisor,
+            denominator: sign * denominator / divisor,
+            pi_power: 0,
+        }
+    }
+
+    /// `mantissa * 10^exponent`, e.g. `Factor::decimal(45359237, -8)` is 0.45359237.
+    ///
+    /// Panics if the power of ten doesn't fit in an `i128`.
+    pub const fn decimal(mantissa: i128, exponent: i8) -> Self {
+        let power = match 10i128.checked_pow(exponent.unsigned_abs() as u32) {
+            Some(power) => power,
+            None => panic!("Factor power of ten is too large."),
+        };
+        if exponent >= 0 {
+            Self::integer(mantissa * power)
+        } else {
+            Self::ratio(mantissa, power)
+        }
+    }
+
+    /// Multiplies the factor by `π^power`.
+    pub const fn times_pi(self, power: i8) -> Self {
+        Self {
+            pi_power: self.pi_power + power,
+            ..self
+        }
+    }
+
+    pub const fn numerator(self) -> i128 {
+        self.numerator
+    }
+
+    pub const fn denominator(self) -> i128 {
+        self.denominator
+    }
+
+    pub const fn pi_power(self) -> i8 {
+        self.pi_power
+    }
+
+    pub const fn is_zero(self) -> bool {
+        self.numerator == 0
+    }
+
+    /// `10^power` or `None` if it doesn't fit in an `i128`.
+    pub fn checked_power_of_ten(power: i32) -> Option<Self> {
+        let magnitude = 10i128.checked_pow(power.unsigned_abs())?;
+        Some(if power >= 0 {
+            Self::integer(magnitude)
+        } else {
+            Self::ratio(1, magnitude)
+        })
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Cancel common factors first so that intermediate values stay small
+        let left = gcd(self.numerator, rhs.denominator).max(1);
+        let right = gcd(rhs.numerator, self.denominator).max(1);
+        Some(Self {
+            numerator: (self.numerator / left).checked_mul(rhs.numerator / right)?,
+            denominator: (self.denominator / right).checked_mul(rhs.denominator / left)?,
+            pi_power: self.pi_power.checked_add(rhs.pi_power)?,
+        })
+    }
+
+    /// Returns `None` if the factor is zero or the result overflows.
+    pub fn checked_recip(self) -> Option<Self> {
+        if self.numerator == 0 {
+            return None;
+        }
+        let sign = self.numerator.signum();
+        Some(Self {
+            numerator: sign * self.denominator,
+            denominator: sign.checked_mul(self.numerator)?,
+            pi_power: self.pi_power.checked_neg()?,
+        })
+    }
+
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.pi_power != rhs.pi_power && !self.is_zero() && !rhs.is_zero() {
+            return None;
+        }
+        let pi_power = if self.is_zero() {
+            rhs.pi_power
+        } else {
+            self.pi_power
+        };
+        let numerator = self
+            .numerator
+            .checked_mul(rhs.denominator)?
+            .checked_add(rhs.numerator.checked_mul(self.denominator)?)?;
+        let denominator = self.denominator.checked_mul(rhs.denominator)?;
+        Some(Self {
+            pi_power,
+            ..Self::ratio(numerator, denominator)
+        })
+    }
+
+    pub fn checked_pow(self, power: i32) -> Option<Self> {
+        let base = if power < 0 {
+            self.checked_recip()?
+        } else {
+            self
+        };
+        let exponent = power.unsigned_abs();
+        Some(Self {
+            numerator: base.numerator.checked_pow(exponent)?,
+            denominator: base.denominator.checked_pow(exponent)?,
+            pi_power: base.pi_power.checked_mul(i8::try_from(exponent).ok()?)?,
+        })
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+            * std::f64::consts::PI.powi(self.pi_power as i32)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+}
+
+impl fmt::Display for Factor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.numerator)?;
+        if self.denominator != 1 {
+            write!(f, "/{}", self.denominator)?;
+        }
+        match self.pi_power {
+            0 => Ok(()),
+            1 => write!(f, "⋅π"),
+            n => write!(f, "⋅π^{n}"),
+        }
+    }
+}
+
+/// A unit which converts to a `DerivedUnit` by the formula
+/// `number * factor + offset` (e.g. hours to seconds or degrees Celsius to kelvin).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaledUnit {
+    pub(crate) symbol: Cow<'static, str>,
+    pub(crate) unit: DerivedUnit,
+    pub(crate) factor: Factor,
+    /// Measured in `unit`
+    pub(crate) offset: Factor,
+}
+
+impl ScaledUnit {
+    /// Panics if the factor is zero.
+    pub const fn new(symbol: &'static str, unit: DerivedUnit, factor: Factor) -> Self {
+        Self::with_offset(symbol, unit, factor, Factor::ZERO)
+    }
+
+    /// A unit with an offset, measured in the SI unit, that is added after scaling
+    /// (e.g. 273.15 K for degrees Celsius).
+    ///
+    /// Panics if the factor is zero.
+    pub const fn with_offset(
+        symbol: &'static str,
+        unit: DerivedUnit,
+        factor: Factor,
+        offset: Factor,
+    ) -> Self {
+        if factor.is_zero() {
+            panic!("ScaledUnit factor must not be zero.")
+        }
+        Self {
+            symbol: Cow::Borrowed(symbol),
+            unit,
+            factor,
+            offset,
+        }
+    }
+
+    /// The SI unit with a prefix on its first symbol (e.g. kN).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the factor can't be represented (e.g. ym² is 10⁻⁴⁸ m²),
+    /// see `checked_prefixed`.
+    pub fn prefixed(prefix: Prefix, unit: DerivedUnit) -> Self {
+        Self::checked_prefixed(prefix, unit)
+            .expect("prefixed unit's factor should be representable")
+    }
+
+    /// The non-panicking version of `prefixed`,
+    /// returning `UnitError::Overflow` if the factor can't be represented.
+    pub fn checked_prefixed(prefix: Prefix, unit: DerivedUnit) -> Result<Self, UnitError> {
+        let exponents = unit.symbol_components().map(|(n, _)| n);
+        let power = prefix_power_of_ten(prefix, &exponents);
+        Ok(Self {
+            symbol: Cow::Owned(crate::format::prefixed_unit_symbol(&unit, prefix)),
+            unit,
+            factor: Factor::checked_power_of_ten(power).ok_or(UnitError::Overflow)?,
+            offset: Factor::ZERO,
+        })
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// The SI unit this unit is a multiple of
+    pub fn unit(&self) -> DerivedUnit {
+        self.unit
+    }
+
+    pub fn factor(&self) -> Factor {
+        self.factor
+    }
+
+    pub fn offset(&self) -> Factor {
+        self.offset
+    }
+
+    /// The same unit without its offset, for converting differences
+    /// (e.g. a change of 1 °C is a change of 1 K).
+    pub fn without_offset(&self) -> Self {
+        Self {
+            offset: Factor::ZERO,
+            ..self.clone()
+        }
+    }
+}
+
+impl fmt::Display for ScaledUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("Unit '{unit}' can't be converted to '{scaled}' which is a multiple of '{expected}'")]
+pub struct ScaledUnitMismatch {
+    pub unit: DerivedUnit,
+    /// The symbol of the scaled unit
+    pub scaled: Cow<'static, str>,
+    /// The SI unit the scaled unit is a multiple of
+    pub expected: DerivedUnit,
+}
+
+/// A number measured in a `ScaledUnit` (e.g. 1.5 h)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaledValue<Number> {
+    pub(crate) unit: ScaledUnit,
+    pub(crate) number: Number,
+}
+
+impl<Number> ScaledValue<Number> {
+    pub fn new(number: Number, unit: ScaledUnit) -> Self {
+        Self { unit, number }
+    }
+
+    pub fn unit(&self) -> &ScaledUnit {
+        &self.unit
+    }
+
+    pub fn number(&self) -> &Number {
+        &self.number
+    }
+}
+
+impl<Number> fmt::Display for ScaledValue<Number>
+where
+    Number: fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.number, self.unit)
+    }
+}
+
+/// A number which can be converted between scaled units.
+pub trait ScalableNumber: Copy + Add<Output = Self> + Sub<Output = Self> {
+    /// The closest number to the factor
+    fn from_factor(factor: Factor) -> Self;
+
+    /// Multiplies by the numerator before dividing by the denominator
+    /// so that exact conversions (e.g. 1.5 t to 1500 kg) stay exact.
+    fn mul_factor(self, factor: Factor) -> Self;
+}
+
+macro_rules! impl_scalable_number {
+    ($type:ident, $to_number:ident) => {
+        impl ScalableNumber for $type {
+            fn from_factor(factor: Factor) -> Self {
+                factor.$to_number()
+            }
+
+            fn mul_factor(self, factor: Factor) -> Self {
+                let rational = self * factor.numerator as $type / factor.denominator as $type;
+                match factor.pi_power {
+                    0 => rational,
+                    n => rational * core::$type::consts::PI.powi(n as i32),
+                }
+            }
+        }
+    };
+}
+
+impl_scalable_number!(f32, to_f32);
+impl_scalable_number!(f64, to_f64);
+
+impl<Number: ScalableNumber> DerivedValue<Number> {
+    /// The number of `unit`s this value is equal to (e.g. 7200 s is 2 h).
+    pub fn in_unit(&self, unit: &ScaledUnit) -> Result<Number, ScaledUnitMismatch> {
+        if self.unit != unit.unit {
+            return Err(ScaledUnitMismatch {
+                unit: self.unit,
+                scaled: unit.symbol.clone(),
+                expected: unit.unit,
+            });
+        }
+        let offset = Number::from_factor(unit.offset);
+        let recip = unit
+            .factor
+            .checked_recip()
+            .expect("scaled unit factors should be non-zero");
+        Ok((self.number - offset).mul_factor(recip))
+    }
+
+    /// Converts this value into a value measured in `unit`.
+    pub fn to_unit(&self, unit: &ScaledUnit) -> Result<ScaledValue<Number>, ScaledUnitMismatch> {
+        Ok(ScaledValue {
+            number: self.in_unit(unit)?,
+            unit: unit.clone(),
+        })
+    }
+
+    /// Constructs a value from a number of `unit`s (e.g. 2 h is 7200 s).
+    pub fn from_unit(number: Number, unit: &ScaledUnit) -> Self {
+        let offset = Number::from_factor(unit.offset);
+        DerivedValue {
+            unit: unit.unit,
+            number: number.mul_factor(unit.factor) + offset,
+        }
+    }
+}
+
+impl<Number: ScalableNumber> BaseValue<Number> {
+    /// The number of `unit`s this value is equal to (e.g. 7200 s is 2 h).
+    pub fn in_unit(&self, unit: &ScaledUnit) -> Result<Number, ScaledUnitMismatch> {
+        self.to_derived().in_unit(unit)
+    }
+
+    /// Converts this value into a value measured in `unit`.
+    pub fn to_unit(&self, unit: &ScaledUnit) -> Result<ScaledValue<Number>, ScaledUnitMismatch> {
+        self.to_derived().to_unit(unit)
+    }
+
+    /// Constructs a value from a number of `unit`s (e.g. 2 h is 7200 s).
+    pub fn from_unit(number: Number, unit: &ScaledUnit) -> Self {
+        DerivedValue::from_unit(number, unit).to_base()
+    }
+}
+
+impl<Number: ScalableNumber> ScaledValue<Number> {
+    pub fn to_derived(&self) -> DerivedValue<Number> {
+        DerivedValue::from_unit(self.number, &self.unit)
+    }
+}
+
+impl<Number: ScalableNumber> From<ScaledValue<Number>> for DerivedValue<Number> {
+    fn from(value: ScaledValue<Number>) -> Self {
+        value.to_derived()
+    }
+}
+
+/// Adds a constructor for each scaled unit to `BaseValue` and `DerivedValue`
+/// (e.g. `feet => FOOT` adds `DerivedValue::feet`).
+macro_rules! scaled_constructors {
+    ($($name:ident => $unit:ident),* $(,)?) => {
+        impl<Number: $crate::scaled::ScalableNumber> $crate::base::BaseValue<Number> {
+            $(
+                #[doc = concat!("Constructs a value from a number of `", stringify!($unit), "`, see `from_unit`.")]
+                pub fn $name(number: Number) -> Self {
+                    Self::from_unit(number, &$unit)
+                }
+            )*
+        }
+
+        impl<Number: $crate::scaled::ScalableNumber> $crate::derived::DerivedValue<Number> {
+            $(
+                #[doc = concat!("Constructs a value from a number of `", stringify!($unit), "`, see `from_unit`.")]
+                pub fn $name(number: Number) -> Self {
+                    Self::from_unit(number, &$unit)
+                }
+            )*
+        }
+    };
+}
+pub(crate) use scaled_constructors;
+
+/// minute (min)
+pub const MINUTE: ScaledUnit = ScaledUnit::new("min", derived::SECOND, Factor::integer(60));
+
+/// hour (h)
+pub const HOUR: ScaledUnit = ScaledUnit::new("h", derived::SECOND, Factor::integer(3600));
+
+/// day (d)
+pub const DAY: ScaledUnit = ScaledUnit::new("d", derived::SECOND, Factor::integer(86400));
+
+/// gram (g)
+pub const GRAM: ScaledUnit = ScaledUnit::new("g", derived::KILOGRAM, Factor::ratio(1, 1000));
+
+/// tonne (t)
+pub const TONNE: ScaledUnit = ScaledUnit::new("t", derived::KILOGRAM, Factor::integer(1000));
+
+/// litre (L)
+pub const LITRE: ScaledUnit = ScaledUnit::new(
+    "L",
+    DerivedUnit {
+        base: crate::base::BaseUnit {
+            meter: 3,
+            ..crate::base::UNITLESS
+        },
+        ..derived::UNITLESS
+    },
+    Factor::ratio(1, 1000),
+);
+
+/// hectare (ha)
+pub const HECTARE: ScaledUnit = ScaledUnit::new("ha", derived::METER_SQ, Factor::integer(10000));
+
+/// bar (bar)
+pub const BAR: ScaledUnit = ScaledUnit::new("bar", derived::PASCAL, Factor::integer(100000));
+
+/// electronvolt (eV)
+pub const ELECTRONVOLT: ScaledUnit =
+    ScaledUnit::new("eV", derived::JOULE, Factor::decimal(1602176634, -28));
+
+/// kilowatt-hour (kWh)
+pub const KILOWATT_HOUR: ScaledUnit =
+    ScaledUnit::new("kWh", derived::JOULE, Factor::integer(3600000));
+
+/// degree Celsius (°C)
+pub const DEGREE_CELSIUS: ScaledUnit = ScaledUnit::with_offset(
+    "°C",
+    derived::KELVIN,
+    Factor::ONE,
+    Factor::decimal(27315, -2),
+);
+
+/// degree Fahrenheit (°F)
+pub const DEGREE_FAHRENHEIT: ScaledUnit = ScaledUnit::with_offset(
+    "°F",
+    derived::KELVIN,
+    Factor::ratio(5, 9),
+    Factor::ratio(45967, 180),
+);
+
+/// degree Rankine (°R)
+pub const DEGREE_RANKINE: ScaledUnit = ScaledUnit::new("°R", derived::KELVIN, Factor::ratio(5, 9));
+
+/// degree (°)
+pub const DEGREE: ScaledUnit =
+    ScaledUnit::new("°", derived::RADIAN, Factor::ratio(1, 180).times_pi(1));
+
+/// arcminute (′)
+pub const ARCMINUTE: ScaledUnit =
+    ScaledUnit::new("′", derived::RADIAN, Factor::ratio(1, 10800).times_pi(1));
+
+/// revolution (rev)
+pub const REVOLUTION: ScaledUnit =
+    ScaledUnit::new("rev", derived::RADIAN, Factor::integer(2).times_pi(1));
+
+/// The scaled units that can be parsed by symbol
+pub(crate) const SCALED_UNITS: [&ScaledUnit; 16] = [
+    &MINUTE,
+    &HOUR,
+    &DAY,
+    &GRAM,
+    &TONNE,
+    &LITRE,
+    &HECTARE,
+    &BAR,
+    &ELECTRONVOLT,
+    &KILOWATT_HOUR,
+    &DEGREE_CELSIUS,
+    &DEGREE_FAHRENHEIT,
+    &DEGREE_RANKINE,
+    &DEGREE,
+    &ARCMINUTE,
+    &REVOLUTION,
+];
+
+/// Finds a scaled unit by its symbol, including the `customary` and `cgs` units
+pub fn lookup_symbol(symbol: &str) -> Option<&'static ScaledUnit> {
+    SCALED_UNITS
+        .into_iter()
+        .chain(customary::UNITS)
+        .chain(cgs::UNITS)
+        .find(|unit| unit.symbol == symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base;
+
+    #[test]
+    fn test_factor_arithmetic() {
+        let third = Factor::ratio(2, 6);
+        assert_eq!(Factor::ratio(1, 3), third);
+        assert_eq!(Some(Factor::ONE), third.checked_mul(Factor::integer(3)));
+        assert_eq!(Some(Factor::integer(3)), third.checked_recip());
+        assert_eq!(Some(Factor::ratio(1, 27)), third.checked_pow(3));
+        assert_eq!(Some(Factor::integer(9)), third.checked_pow(-2));
+        assert_eq!(Some(Factor::ratio(2, 3)), third.checked_add(third));
+        assert_eq!(
+            Factor::ratio(45359237, 100000000),
+            Factor::decimal(45359237, -8)
+        );
+        assert_eq!(None, Factor::ZERO.checked_recip());
+        assert_eq!(
+            None,
+            Factor::decimal(1, 30).checked_mul(Factor::decimal(1, 30))
+        );
+
+        let degree = Factor::ratio(1, 180).times_pi(1);
+        assert!((degree.to_f64() - std::f64::consts::PI / 180.0).abs() < 1e-15);
+        assert_eq!("1/180⋅π", degree.to_string());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let time = DerivedValue::seconds(7200.0f64);
+        assert_eq!(Ok(2.0), time.in_unit(&HOUR));
+        assert_eq!(Ok(120.0), time.in_unit(&MINUTE));
+        assert_eq!("2 h", time.to_unit(&HOUR).unwrap().to_string());
+        assert_eq!(time, DerivedValue::from_unit(2.0f64, &HOUR));
+        assert_eq!(time, DerivedValue::from(time.to_unit(&DAY).unwrap()));
+
+        let mass = base::BaseValue::kilograms(1500.0f32);
+        assert_eq!(Ok(1.5), mass.in_unit(&TONNE));
+        assert_eq!(Ok(1.5e6), mass.in_unit(&GRAM));
+
+        let temperature = DerivedValue::kelvin(300.0f64);
+        let celsius = temperature.in_unit(&DEGREE_CELSIUS).unwrap();
+        assert!((celsius - 26.85).abs() < 1e-12);
+        assert_eq!(
+            DerivedValue::kelvin(273.15f64),
+            DerivedValue::from_unit(0.0f64, &DEGREE_CELSIUS)
+        );
+
+        let angle = DerivedValue::from_unit(90.0f64, &DEGREE);
+        assert!((angle.number - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+        assert_eq!(derived::RADIAN, angle.unit);
+        assert!((angle.in_unit(&ARCMINUTE).unwrap() - 5400.0).abs() < 1e-9);
+        assert!((angle.in_unit(&REVOLUTION).unwrap() - 0.25).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let error = DerivedValue::meters(1.0f64).in_unit(&HOUR).unwrap_err();
+        assert_eq!(derived::METER, error.unit);
+        assert_eq!(
+            "Unit 'm' can't be converted to 'h' which is a multiple of 's'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_prefixed() {
+        let kilonewton = ScaledUnit::prefixed(Prefix::Kilo, derived::NEWTON);
+        assert_eq!("kN", kilonewton.symbol());
+        assert_eq!(Factor::integer(1000), kilonewton.factor());
+        assert_eq!(
+            Ok(3.0),
+            DerivedValue::newton(3000.0f64).in_unit(&kilonewton)
+        );
+
+        let milligram = ScaledUnit::prefixed(Prefix::Milli, derived::KILOGRAM);
+        assert_eq!("mg", milligram.symbol());
+        assert_eq!(Factor::ratio(1, 1000000), milligram.factor());
+
+        // 10⁻⁴⁸ and 10⁴⁸ don't fit in a Factor
+        assert_eq!(
+            Err(UnitError::Overflow),
+            ScaledUnit::checked_prefixed(Prefix::Yocto, derived::METER_SQ)
+        );
+        assert_eq!(
+            Err(UnitError::Overflow),
+            ScaledUnit::checked_prefixed(Prefix::Yotta, derived::METER_SQ)
+        );
+        assert_eq!(
+            Ok(milligram),
+            ScaledUnit::checked_prefixed(Prefix::Milli, derived::KILOGRAM)
+        );
+    }
+}
@@ -0,0 +1,891 @@
+//! Finding the simplest way to write a unit using the named derived units.
+//!
+//! The simplest form is the one with the smallest sum of absolute exponents
+//! (e.g. `N` rather than `J/m` or `kg⋅m/s²`, and `Pa²⋅W/(kg³⋅s)` rather than `/s⁸`).
+//! It is found with an A* search over the powers of the named units, starting from the simplest
+//! of the unit as given, the result of the greedy algorithm (see `DerivedUnit::simplify_greedy`),
+//! and the unit as a power of its simplified root (e.g. `N¹⁰` for `kg¹⁰⋅m¹⁰/s²⁰`).
+//! The search is guided by the cost of the unit when any fractional powers of the named units
+//! can be used, which is never more than the cost of a form, so it only stops once no form
+//! can be cheaper than the one it returns (which is never worse than the greedy form).
+//!
+//! Ties are broken deterministically between the forms the search finds:
+//! 1. Forms with fewer symbols are preferred (e.g. `W/m²` rather than `N/(m⋅s)`).
+//! 2. Then forms with fewer named units (e.g. `s/(kg⋅A)` rather than `/(A⋅C⋅T)`).
+//! 3. Then forms whose named exponents are largest when compared in the order of the
+//!    SI table (rad, sr, Hz, N, Pa, J, W, C, V, F, Ω, S, Wb, T, H, lm, lx, Bq, Gy, Sv, kat),
+//!    which prefers earlier units and positive exponents (e.g. `N/m` rather than `Pa⋅m`
+//!    and `S` rather than `/Ω`).
+//!
+//! `SimplifyOptions` can change the cost of each named unit, forbid some of them,
+//! or give a preferred form for a unit, with the default options matching `DerivedUnit::simplify`.
+//!
+//! Hertz and becquerel are never used since `/s` is just as simple
+//! and sievert is never used since it's the same as gray.
+//! Radians, steradians, lumens, and lux are kept as they were given since angles are dimensionless,
+//! but `cd⋅sr` is written as `lm` and `lm/m²` as `lx`
+//! (while `cd/m²` is left alone since it's a luminance rather than an illuminance).
+
+use core::fmt;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BTreeMap, BinaryHeap, btree_map::Entry},
+    sync::OnceLock,
+};
+
+use crate::{
+    base::BaseUnit,
+    derived::{DerivedUnit, NamedUnit},
+    exponents::UnitExponent,
+};
+
+fn base_exponents<ExponentType: UnitExponent>(unit: BaseUnit<ExponentType>) -> [ExponentType; 7] {
+    [
+        unit.kilogram,
+        unit.meter,
+        unit.second,
+        unit.mole,
+        unit.ampere,
+        unit.kelvin,
+        unit.candela,
+    ]
+}
+
+/// The base exponents as signed magnitudes (in quarters for `FractionalExponent`).
+fn signed_magnitudes<ExponentType: UnitExponent>(unit: BaseUnit<ExponentType>) -> [f64; 7] {
+    base_exponents(unit).map(|exponent| {
+        let magnitude = f64::from(exponent.magnitude());
+        if exponent < ExponentType::ZERO {
+            -magnitude
+        } else {
+            magnitude
+        }
+    })
+}
+
+/// The weights `w` for the base exponents with `|wᵢ| ≤ 1` and `|w⋅b| ≤ 1` for the base units `b`
+/// of every named unit the search can use, so no step changes the weighted sum of the remaining
+/// base exponents by more than it costs.
+///
+/// The largest weighted sum over these weights is the cost of the cheapest form when any
+/// fractional powers of the named units are allowed (by linear programming duality),
+/// which is a lower bound on the cost of every form.
+/// It's the largest at one of the vertices of the weights, which are found once by solving
+/// for every combination of constraints that meet at a point.
+struct DualBound {
+    /// Whether a named unit contains the base unit together with another one or squared
+    /// (e.g. kilograms in newtons), since the others always have a weight of ±1
+    coupled: [bool; 7],
+    /// The vertices with a weight of zero for the base units that aren't coupled,
+    /// with only one of each pair of opposite vertices
+    vertices: Vec<[f64; 7]>,
+}
+
+impl DualBound {
+    fn get() -> &'static Self {
+        static BOUND: OnceLock<DualBound> = OnceLock::new();
+        BOUND.get_or_init(Self::new)
+    }
+
+    fn new() -> Self {
+        // Lux is never used by the search (see `simplify_with`)
+        let named: Vec<[f64; 7]> = NamedUnit::ALL
+            .iter()
+            .filter(|named| **named != NamedUnit::Lux)
+            .map(|named| signed_magnitudes(named.unit().to_base()))
+            .filter(|base| base.iter().any(|exponent| *exponent != 0.0))
+            .collect();
+
+        let mut coupled = [false; 7];
+        for base in &named {
+            let support = base.iter().filter(|exponent| **exponent != 0.0).count();
+            for (coupled, exponent) in coupled.iter_mut().zip(base) {
+                *coupled |= *exponent != 0.0 && (support > 1 || exponent.abs() > 1.0);
+            }
+        }
+        let dims: Vec<usize> = (0..7).filter(|index| coupled[*index]).collect();
+        let dim = dims.len();
+
+        // The normals of the constraints restricted to the coupled base units
+        let mut normals: Vec<Vec<f64>> = Vec::new();
+        let unit_vectors = dims.iter().map(|index| {
+            let mut base = [0.0; 7];
+            base[*index] = 1.0;
+            base
+        });
+        for base in unit_vectors.chain(named.iter().copied()) {
+            let normal: Vec<f64> = dims.iter().map(|index| base[*index]).collect();
+            let negated: Vec<f64> = normal.iter().map(|x| -x).collect();
+            if normal.iter().any(|x| *x != 0.0)
+                && !normals.contains(&normal)
+                && !normals.contains(&negated)
+            {
+                normals.push(normal);
+            }
+        }
+        let is_feasible = |weight: &[f64]| {
+            normals.iter().all(|normal| {
+                let dot: f64 = normal.iter().zip(weight).map(|(a, b)| a * b).sum();
+                dot.abs() <= 1.0 + 1e-9
+            })
+        };
+
+        // Keyed by the rounded weights to remove duplicates
+        let mut vertices = BTreeMap::new();
+        let mut chosen: Vec<usize> = (0..dim).collect();
+        loop {
+            let rows: Vec<&[f64]> = chosen
+                .iter()
+                .map(|index| normals[*index].as_slice())
+                .collect();
+            if let Some(inverse) = invert(&rows) {
+                for signs in 0..1u32 << dim {
+                    let target: Vec<f64> = (0..dim)
+                        .map(|i| if signs >> i & 1 == 0 { 1.0 } else { -1.0 })
+                        .collect();
+                    let weight: Vec<f64> = inverse
+                        .iter()
+                        .map(|row| row.iter().zip(&target).map(|(a, b)| a * b).sum())
+                        .collect();
+                    if is_feasible(&weight) {
+                        let mut vertex = [0.0; 7];
+                        for (index, w) in dims.iter().zip(weight) {
+                            vertex[*index] = w;
+                        }
+                        // Only one of `w` and `-w` is kept since the weights are symmetric
+                        let key = vertex.map(|w| (w * 1e6).round() as i64);
+                        let negated = key.map(|w| -w);
+                        if !vertices.contains_key(&negated) {
+                            vertices.entry(key).or_insert(vertex);
+                        }
+                    }
+                }
+            }
+            // The next combination of `dim` constraints in lexicographic order
+            let Some(i) = (0..dim)
+                .rev()
+                .find(|i| chosen[*i] < normals.len() - dim + i)
+            else {
+                break;
+            };
+            chosen[i] += 1;
+            for j in i + 1..dim {
+                chosen[j] = chosen[j - 1] + 1;
+            }
+        }
+
+        Self {
+            coupled,
+            vertices: vertices.into_values().collect(),
+        }
+    }
+
+    /// A lower bound on the cost of any form of the base units.
+    fn bound<ExponentType: UnitExponent>(&self, unit: BaseUnit<ExponentType>) -> u32 {
+        let exponents = signed_magnitudes(unit);
+        let uncoupled: f64 = exponents
+            .iter()
+            .zip(self.coupled)
+            .filter(|(_, coupled)| !coupled)
+            .map(|(exponent, _)| exponent.abs())
+            .sum();
+        let mut coupled: f64 = 0.0;
+        for vertex in &self.vertices {
+            let mut sum = 0.0;
+            for i in 0..7 {
+                sum += vertex[i] * exponents[i];
+            }
+            coupled = coupled.max(sum.abs());
+        }
+        // Costs are whole numbers so the bound can be rounded up (allowing for rounding errors)
+        (uncoupled + coupled - 1e-6).ceil().max(0.0) as u32
+    }
+}
+
+/// The inverse of a square matrix, or `None` if it's singular.
+fn invert(rows: &[&[f64]]) -> Option<Vec<Vec<f64>>> {
+    let n = rows.len();
+    let mut matrix: Vec<Vec<f64>> = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut row = row.to_vec();
+            row.extend((0..n).map(|j| if i == j { 1.0 } else { 0.0 }));
+            row
+        })
+        .collect();
+    for column in 0..n {
+        let pivot = (column..n).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .total_cmp(&matrix[*b][column].abs())
+        })?;
+        if matrix[pivot][column].abs() < 1e-9 {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        let scale = matrix[column][column];
+        for x in &mut matrix[column] {
+            *x /= scale;
+        }
+        let pivot_row = matrix[column].clone();
+        for (index, row) in matrix.iter_mut().enumerate() {
+            if index != column {
+                let factor = row[column];
+                for (x, pivot) in row.iter_mut().zip(&pivot_row) {
+                    *x -= factor * pivot;
+                }
+            }
+        }
+    }
+    Some(matrix.into_iter().map(|row| row[n..].to_vec()).collect())
+}
+
+/// How `DerivedUnit::simplify_with` chooses the simplest form of a unit.
+///
+/// The cost of a form is the sum of the absolute values of its exponents
+/// with the exponents of each named unit multiplied by that unit's weight.
+/// The default options match `DerivedUnit::simplify`.
+///
+/// ```
+/// use physical_units::{derived::{self, NamedUnit}, simplify::SimplifyOptions};
+///
+/// let torque = derived::KILOGRAM * derived::METER_SQ / derived::SECOND.pow(2);
+/// assert_eq!("J", torque.simplify().to_string());
+///
+/// let options = SimplifyOptions::default().forbid(NamedUnit::Joule);
+/// assert_eq!("m⋅N", torque.simplify_with(&options).to_string());
+/// ```
+#[derive(Clone)]
+pub struct SimplifyOptions<ExponentType = i8> {
+    /// Indexed in `NamedUnit::ALL` order
+    weights: [u16; NamedUnit::ALL.len()],
+    /// Indexed in `NamedUnit::ALL` order
+    allowed: [bool; NamedUnit::ALL.len()],
+    preferred: Vec<DerivedUnit<ExponentType>>,
+}
+
+impl<ExponentType> Default for SimplifyOptions<ExponentType> {
+    fn default() -> Self {
+        Self {
+            weights: [1; NamedUnit::ALL.len()],
+            allowed: [true; NamedUnit::ALL.len()],
+            preferred: Vec::new(),
+        }
+    }
+}
+
+impl<ExponentType> SimplifyOptions<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// Sets how much each exponent of the named unit costs (1 by default).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the weight is zero.
+    pub fn weight(mut self, unit: NamedUnit, weight: u16) -> Self {
+        assert!(weight > 0, "the weight of a named unit can't be zero");
+        self.weights[unit as usize] = weight;
+        self
+    }
+
+    /// Never uses the named unit.
+    pub fn forbid(mut self, unit: NamedUnit) -> Self {
+        self.allowed[unit as usize] = false;
+        self
+    }
+
+    /// Only uses the given named units.
+    pub fn allow_only(mut self, units: impl IntoIterator<Item = NamedUnit>) -> Self {
+        self.allowed = [false; NamedUnit::ALL.len()];
+        for unit in units {
+            self.allowed[unit as usize] = true;
+        }
+        self
+    }
+
+    /// Always writes units with the same base units as `form` as `form`
+    /// (e.g. torque as `N⋅m`), taking priority over the other options.
+    pub fn prefer(mut self, form: DerivedUnit<ExponentType>) -> Self {
+        self.preferred.push(form);
+        self
+    }
+}
+
+impl<ExponentType> fmt::Debug for SimplifyOptions<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimplifyOptions")
+            .field("weights", &self.weights)
+            .field("allowed", &self.allowed)
+            .field("preferred", &self.preferred)
+            .finish()
+    }
+}
+
+/// A named unit the search can use
+#[derive(Clone, Copy)]
+struct Direction<ExponentType> {
+    /// The smallest power of the named unit that the exponent type
+    /// can represent (e.g. J¼ for `FractionalExponent`)
+    named: DerivedUnit<ExponentType>,
+    /// The base units of `named`
+    base: BaseUnit<ExponentType>,
+    /// The exponent of the named unit in `named`
+    exponent: ExponentType,
+    /// The magnitude of `exponent` times the unit's weight
+    cost: u32,
+}
+
+#[derive(Clone, Copy)]
+struct State<ExponentType> {
+    /// The base units that haven't been replaced by named units
+    remainder: BaseUnit<ExponentType>,
+    /// The power of each direction in `NamedUnit::ALL` order
+    counts: [i8; NamedUnit::ALL.len()],
+    /// The sum of the costs of the directions times the magnitudes of their powers
+    named: u32,
+}
+
+struct Search<ExponentType> {
+    /// Indexed in `NamedUnit::ALL` order with `None` for units that are never used
+    directions: [Option<Direction<ExponentType>>; NamedUnit::ALL.len()],
+}
+
+impl<ExponentType: UnitExponent> Search<ExponentType> {
+    fn new(options: &SimplifyOptions<ExponentType>) -> Self {
+        let candidates = NamedUnit::ALL.map(|named| {
+            let index = named as usize;
+            // Lux contains steradians so it's only used for lm/m² (see `simplify_with`)
+            if !options.allowed[index] || named == NamedUnit::Lux {
+                return None;
+            }
+            let named = named.unit().cast_exponents::<ExponentType>();
+            let named = [4, 2]
+                .into_iter()
+                .find_map(|root| named.checked_root(root).ok())
+                .unwrap_or(named);
+            let base = named.to_base();
+            let exponent = named.named_exponents()[index];
+            let cost = u32::from(named.magnitude()) * u32::from(options.weights[index]);
+            // Units that are no simpler than their base units (e.g. Hz) are never needed
+            if u32::from(base.magnitude()) <= cost {
+                return None;
+            }
+            Some(Direction {
+                named,
+                base,
+                exponent,
+                cost,
+            })
+        });
+        // Units with the same base units as a cheaper or earlier one are never preferred
+        let mut directions = candidates;
+        for (index, direction) in directions.iter_mut().enumerate() {
+            let is_redundant = direction.as_ref().is_some_and(|direction| {
+                candidates
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(i, other)| Some((i, other.as_ref()?)))
+                    .any(|(other_index, other)| {
+                        other.base == direction.base
+                            && (other.cost, other_index) < (direction.cost, index)
+                    })
+            });
+            if is_redundant {
+                *direction = None;
+            }
+        }
+
+        Self { directions }
+    }
+
+    /// The weighted sum of the absolute values of all the exponents
+    fn cost(&self, state: &State<ExponentType>) -> u32 {
+        state.named + u32::from(state.remainder.magnitude())
+    }
+
+    /// The number of base and named units used
+    fn symbols(&self, state: &State<ExponentType>) -> u32 {
+        state.counts.iter().filter(|count| **count != 0).count() as u32
+            + base_exponents(state.remainder)
+                .into_iter()
+                .filter(|exponent| *exponent != ExponentType::ZERO)
+                .count() as u32
+    }
+
+    /// Whether `state` is simpler than `other` including the tie-breaks.
+    fn is_simpler(&self, state: &State<ExponentType>, other: &State<ExponentType>) -> bool {
+        let ordering = self
+            .cost(state)
+            .cmp(&self.cost(other))
+            .then_with(|| self.symbols(state).cmp(&self.symbols(other)))
+            .then_with(|| state.named.cmp(&other.named))
+            .then_with(|| other.counts.cmp(&state.counts));
+        ordering == Ordering::Less
+    }
+
+    /// Applies a direction `sign` times (either 1 or -1).
+    fn apply(
+        &self,
+        state: &State<ExponentType>,
+        index: usize,
+        sign: i8,
+    ) -> Option<State<ExponentType>> {
+        let direction = self.directions[index].as_ref()?;
+        let count = state.counts[index].checked_add(sign)?;
+        // The named exponent has to be representable when the unit is built
+        direction.exponent.checked_mul(count)?;
+        let remainder = if sign > 0 {
+            state.remainder.checked_divide(direction.base)
+        } else {
+            state.remainder.checked_multiply(direction.base)
+        };
+        let mut counts = state.counts;
+        counts[index] = count;
+        let named = state.named - u32::from(state.counts[index].unsigned_abs()) * direction.cost
+            + u32::from(count.unsigned_abs()) * direction.cost;
+        Some(State {
+            remainder: remainder.ok()?,
+            counts,
+            named,
+        })
+    }
+
+    /// The unit written with the directions, if it only uses named units that can be used.
+    fn state(&self, unit: DerivedUnit<ExponentType>) -> Option<State<ExponentType>> {
+        let mut counts = [0; NamedUnit::ALL.len()];
+        let mut named = 0;
+        for ((count, exponent), direction) in counts
+            .iter_mut()
+            .zip(unit.named_exponents())
+            .zip(&self.directions)
+        {
+            if exponent == ExponentType::ZERO {
+                continue;
+            }
+            let direction = direction.as_ref()?;
+            let magnitude =
+                i8::try_from(exponent.magnitude() / direction.exponent.magnitude()).ok()?;
+            *count = if exponent < ExponentType::ZERO {
+                -magnitude
+            } else {
+                magnitude
+            };
+            if direction.exponent.checked_mul(*count)? != exponent {
+                return None;
+            }
+            named += u32::from(count.unsigned_abs()) * direction.cost;
+        }
+        Some(State {
+            remainder: unit.base,
+            counts,
+            named,
+        })
+    }
+
+    /// The simplest form of the largest exact root of the unit raised back to the power
+    /// (e.g. `N¹⁰` for `kg¹⁰⋅m¹⁰/s²⁰`)
+    fn power(&self, unit: DerivedUnit<ExponentType>) -> Option<DerivedUnit<ExponentType>> {
+        let base = unit.checked_to_base().ok()?;
+        if base == BaseUnit::unitless() {
+            return None;
+        }
+        let (root, power) = (2..=i8::MAX)
+            .rev()
+            .find_map(|power| Some((base.checked_root(power).ok()?, power)))?;
+        self.run(root.to_derived()).checked_pow(power).ok()
+    }
+
+    /// An A* search over the forms keyed by their remaining base units,
+    /// which finds the cheapest form since `DualBound` never overestimates
+    /// the cost of the rest of a form.
+    fn run(&self, unit: DerivedUnit<ExponentType>) -> DerivedUnit<ExponentType> {
+        // Units whose base exponents don't fit in the exponent type (e.g. `N¹⁰⁰`)
+        // are already written with named units
+        let Ok(remainder) = unit.checked_to_base() else {
+            return unit;
+        };
+        let start = State {
+            remainder,
+            counts: [0; NamedUnit::ALL.len()],
+            named: 0,
+        };
+        let bound = DualBound::get();
+
+        // Good forms to start from let the search skip more states
+        let mut best = start;
+        let candidates = [
+            Some(unit),
+            unit.checked_simplify_greedy().ok(),
+            self.power(unit),
+        ];
+        for candidate in candidates.into_iter().flatten() {
+            if let Some(candidate) = self.state(candidate)
+                && self.is_simpler(&candidate, &best)
+            {
+                best = candidate;
+            }
+        }
+
+        // Each remainder keeps its simplest state, the bound on the cost of the rest of it,
+        // and whether it's been expanded
+        let remaining = bound.bound(start.remainder);
+        let mut states = BTreeMap::from([(start.remainder, (start, remaining, false))]);
+        // Ordered by the estimated cost and then the bound, so ties follow a single form to its end
+        let mut open = BinaryHeap::from([Reverse((remaining, remaining, start.remainder))]);
+        while let Some(Reverse((estimate, _, remainder))) = open.pop() {
+            // Every form left costs at least as much as the best one
+            if estimate >= self.cost(&best) {
+                break;
+            }
+            let Some((state, _, expanded)) = states.get_mut(&remainder) else {
+                continue;
+            };
+            if *expanded {
+                continue;
+            }
+            *expanded = true;
+            let state = *state;
+            for index in 0..self.directions.len() {
+                for sign in [1, -1] {
+                    let Some(candidate) = self.apply(&state, index, sign) else {
+                        continue;
+                    };
+                    if self.is_simpler(&candidate, &best) {
+                        best = candidate;
+                    }
+                    if candidate.named >= self.cost(&best) {
+                        continue;
+                    }
+                    match states.entry(candidate.remainder) {
+                        Entry::Vacant(entry) => {
+                            let remaining = bound.bound(candidate.remainder);
+                            entry.insert((candidate, remaining, false));
+                            let estimate = candidate.named + remaining;
+                            open.push(Reverse((estimate, remaining, candidate.remainder)));
+                        }
+                        Entry::Occupied(mut entry) => {
+                            let (existing, remaining, expanded) = entry.get_mut();
+                            if *expanded {
+                                continue;
+                            }
+                            // Forms with the same remainder cost more the more named units they use
+                            if candidate.named < existing.named {
+                                let estimate = candidate.named + *remaining;
+                                open.push(Reverse((estimate, *remaining, candidate.remainder)));
+                            }
+                            if self.is_simpler(&candidate, existing) {
+                                *existing = candidate;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        self.build(&best)
+    }
+
+    fn build(&self, state: &State<ExponentType>) -> DerivedUnit<ExponentType> {
+        let mut unit = state.remainder.to_derived();
+        for (count, direction) in state.counts.iter().zip(&self.directions) {
+            if let Some(direction) = direction {
+                // The exponent was checked when the direction was applied
+                unit = unit.multiply(direction.named.pow(*count));
+            }
+        }
+        unit
+    }
+}
+
+impl<ExponentType> DerivedUnit<ExponentType>
+where
+    ExponentType: UnitExponent,
+{
+    /// Expresses the unit using the named units so that the sum of the absolute values
+    /// of its exponents is as small as possible, breaking ties as described in the module docs.
+    ///
+    /// Named units are used in the smallest powers the exponent type can represent,
+    /// so with `FractionalExponent` `J⁰⋅⁵/s⁰⋅⁵` becomes `W⁰⋅⁵`.
+    ///
+    /// Units whose base exponents don't fit in the exponent type (e.g. `N¹⁰⁰`) are returned
+    /// as they are. The search takes longer the larger the exponents are,
+    /// so `simplify_greedy` may be preferable for very large units.
+    pub fn simplify(self) -> Self {
+        self.simplify_with(&SimplifyOptions::default())
+    }
+
+    /// Simplifies the unit using the preferred forms, weights, and named units
+    /// of the options, see `SimplifyOptions`.
+    pub fn simplify_with(self, options: &SimplifyOptions<ExponentType>) -> Self {
+        if let Some(preferred) = options
+            .preferred
+            .iter()
+            .find(|preferred| **preferred == self)
+        {
+            return *preferred;
+        }
+        let mut unit = self;
+        let zero = ExponentType::ZERO;
+        // cd⋅sr is written as lm
+        if options.allowed[NamedUnit::Lumen as usize] {
+            let (candela, steradian) = (unit.base.candela, unit.steradian);
+            let lumen = if candela > zero && steradian > zero {
+                candela.min(steradian)
+            } else if candela < zero && steradian < zero {
+                candela.max(steradian)
+            } else {
+                zero
+            };
+            unit.base.candela = candela - lumen;
+            unit.steradian = steradian - lumen;
+            unit.lumen = unit.lumen + lumen;
+        }
+
+        // lx is lm/m², so lux are turned into lumens and then as many as possible back into lux
+        let lux = unit.lux;
+        let meter = unit
+            .base
+            .meter
+            .checked_sub(lux)
+            .and_then(|m| m.checked_sub(lux));
+        if let (Some(meter), Some(lumen)) = (meter, unit.lumen.checked_add(lux)) {
+            unit.base.meter = meter;
+            unit.lumen = lumen;
+            unit.lux = zero;
+        }
+        if options.allowed[NamedUnit::Lux as usize] {
+            let one = ExponentType::ONE;
+            let two = one + one;
+            loop {
+                if unit.lumen >= one && unit.base.meter <= -two {
+                    unit.lumen = unit.lumen - one;
+                    unit.base.meter = unit.base.meter + two;
+                    unit.lux = unit.lux + one;
+                } else if unit.lumen <= -one && unit.base.meter >= two {
+                    unit.lumen = unit.lumen + one;
+                    unit.base.meter = unit.base.meter - two;
+                    unit.lux = unit.lux - one;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        // Angles are dimensionless so the search over base units can't see them
+        // and the units that contain them are kept as they were given
+        let angles = DerivedUnit {
+            radian: unit.radian,
+            steradian: unit.steradian,
+            lumen: unit.lumen,
+            lux: unit.lux,
+            ..DerivedUnit::unitless()
+        };
+        let unit = unit.divide(angles);
+        Search::new(options).run(unit).multiply(angles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{base, derived, exponents::UnitError};
+
+    #[test]
+    fn test_optimal() {
+        let cases = [
+            (base::NEWTON, "N"),
+            (base::JOULE, "J"),
+            (base::JOULE * base::METER, "m⋅J"),
+            (base::UNITLESS / base::SECOND, "/s"),
+            (base::METER_SQ / (base::SECOND * base::SECOND), "Gy"),
+            (base::UNITLESS / base::OHM, "S"),
+            (base::VOLT * base::AMPERE, "W"),
+            (base::KILOGRAM / (base::SECOND * base::SECOND), "N/m"),
+            (base::MOLE / base::SECOND, "kat"),
+            (base::JOULE.pow(10), "J¹⁰"),
+        ];
+        for (unit, expected) in cases {
+            assert_eq!(expected, unit.to_derived().simplify().to_string());
+        }
+
+        // The greedy algorithm is stuck with J/m
+        let force = derived::KILOGRAM * derived::METER / derived::SECOND.pow(2);
+        assert_eq!("J/m", force.simplify_greedy().to_string());
+        assert_eq!("N", force.simplify().to_string());
+
+        // Angles are kept even though they're dimensionless
+        let angular_momentum =
+            derived::KILOGRAM * derived::METER_SQ * derived::RADIAN / derived::SECOND;
+        assert_eq!("s⋅rad⋅J", angular_momentum.simplify().to_string());
+        let intensity = derived::WATT / derived::STERADIAN;
+        assert_eq!("W/sr", intensity.simplify().to_string());
+        let flux = derived::CANDELA * derived::STERADIAN;
+        assert_eq!("lm", flux.simplify().to_string());
+        assert_eq!("lx", (flux / derived::METER_SQ).simplify().to_string());
+        assert_eq!(
+            "s⋅lm",
+            (derived::LUMEN * derived::SECOND).simplify().to_string()
+        );
+    }
+
+    #[test]
+    fn test_lux() {
+        let cases = [
+            (derived::LUMEN / derived::METER_SQ, "lx"),
+            (derived::LUX, "lx"),
+            (derived::LUMEN / derived::METER.pow(3), "lx/m"),
+            (derived::LUX * derived::METER_SQ, "lm"),
+            (derived::METER_SQ / derived::LUMEN, "/lx"),
+            (derived::LUMEN.pow(2) / derived::METER_SQ, "lm⋅lx"),
+            (derived::LUX * derived::SECOND, "s⋅lx"),
+            // A luminance isn't an illuminance
+            (derived::CANDELA / derived::METER_SQ, "cd/m²"),
+        ];
+        for (unit, expected) in cases {
+            let simplified = unit.simplify();
+            assert_eq!(unit, simplified);
+            assert_eq!(expected, simplified.to_string());
+        }
+
+        let options = SimplifyOptions::default().forbid(NamedUnit::Lux);
+        assert_eq!("lm/m²", derived::LUX.simplify_with(&options).to_string());
+    }
+
+    #[test]
+    fn test_large_exponents() {
+        let cases = [
+            (derived::NEWTON.pow(10), "N¹⁰"),
+            (base::NEWTON.pow(10).to_derived(), "N¹⁰"),
+            (base::NEWTON.pow(20).to_derived(), "N²⁰"),
+            (derived::NEWTON.pow(60), "N⁶⁰"),
+            (derived::SECOND.pow(-8), "Pa²⋅W/(kg³⋅s)"),
+            (derived::SECOND.pow(-16), "Pa⁴⋅W²/(kg⁶⋅s²)"),
+            (derived::HERTZ.pow(100), "Pa³²⋅W⁴⋅Gy¹²/kg³⁶"),
+            (
+                (derived::KILOGRAM * derived::METER).pow(40),
+                "kg⁴⁰⋅m⋅J¹³/Pa¹³",
+            ),
+            (derived::JOULE.pow(10) / derived::METER, "N⋅J⁹"),
+            // The greedy algorithm overflows on these
+            (derived::SECOND.pow(124), "kg⁴¹⋅m/(Pa⁴¹⋅Gy²¹)"),
+            (derived::METER.pow(126), "J⁴²/Pa⁴²"),
+        ];
+        for (unit, expected) in cases {
+            let simplified = unit.simplify();
+            assert_eq!(unit, simplified);
+            assert_eq!(expected, simplified.to_string());
+        }
+        assert_eq!(
+            Err(UnitError::Overflow),
+            derived::SECOND.pow(124).checked_simplify_greedy()
+        );
+        assert_eq!(
+            Err(UnitError::Overflow),
+            derived::METER.pow(126).checked_simplify_greedy()
+        );
+    }
+
+    #[test]
+    fn test_ties() {
+        // Fewer symbols are preferred over earlier named units
+        let flux = derived::NEWTON / (derived::METER * derived::SECOND);
+        assert_eq!("W/m²", flux.simplify().to_string());
+        assert_eq!("W/m²", flux.to_base().to_derived().simplify().to_string());
+        // and then fewer named units are preferred (rather than `/(A⋅C⋅T)`)
+        let unit = derived::SECOND / (derived::KILOGRAM * derived::AMPERE);
+        assert_eq!("s/(kg⋅A)", unit.simplify().to_string());
+    }
+
+    #[test]
+    fn test_never_worse_than_greedy() {
+        // The greedy algorithm only gets to `Pa²/(kg⁵⋅m⋅s)` here
+        let unit = derived::UNITLESS
+            / (derived::KILOGRAM * derived::METER).pow(3)
+            / derived::SECOND.pow(5);
+        assert_eq!(9, unit.simplify_greedy().magnitude());
+        assert!(unit.simplify().magnitude() <= 9);
+
+        for kilogram in -3..=3 {
+            for meter in -3..=3 {
+                for second in -6..=6 {
+                    for ampere in -2..=2 {
+                        for candela in -1..=1 {
+                            let unit = base::BaseUnit {
+                                kilogram,
+                                meter,
+                                second,
+                                mole: 0,
+                                ampere,
+                                kelvin: 0,
+                                candela,
+                            }
+                            .to_derived();
+                            // The greedy algorithm writes cd/m² as lx even though it's a luminance
+                            let mut greedy = unit.simplify_greedy();
+                            greedy.base.candela += greedy.lux;
+                            greedy.base.meter -= 2 * greedy.lux;
+                            greedy.lux = 0;
+                            let optimal = unit.simplify();
+                            assert_eq!(unit, optimal);
+                            assert!(
+                                optimal.magnitude() <= greedy.magnitude(),
+                                "{optimal} is worse than {greedy}"
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_options() {
+        let options = SimplifyOptions::default();
+        for unit in [
+            base::NEWTON,
+            base::UNITLESS / base::SECOND,
+            base::KILOGRAM / (base::SECOND * base::SECOND),
+            base::MOLE * base::AMPERE / base::KILOGRAM,
+        ] {
+            let unit = unit.to_derived();
+            assert_eq!(
+                unit.simplify().to_string(),
+                unit.simplify_with(&options).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let torque = derived::NEWTON * derived::METER;
+        let power = derived::VOLT * derived::AMPERE;
+
+        let electrical = SimplifyOptions::default().allow_only([
+            NamedUnit::Watt,
+            NamedUnit::Volt,
+            NamedUnit::Ohm,
+            NamedUnit::Coulomb,
+        ]);
+        assert_eq!("W", power.simplify_with(&electrical).to_string());
+        assert_eq!("s⋅W", torque.simplify_with(&electrical).to_string());
+
+        let mechanical = SimplifyOptions::default().forbid(NamedUnit::Joule);
+        assert_eq!("m⋅N", torque.simplify_with(&mechanical).to_string());
+
+        let weighted = SimplifyOptions::default().weight(NamedUnit::Joule, 3);
+        assert_eq!("m⋅N", torque.simplify_with(&weighted).to_string());
+
+        let preferred = SimplifyOptions::default().prefer(derived::NEWTON * derived::METER);
+        let energy = derived::WATT * derived::SECOND;
+        assert_eq!("m⋅N", energy.simplify_with(&preferred).to_string());
+        assert_eq!("W", power.simplify_with(&preferred).to_string());
+
+        // Sievert is used if it's cheaper than gray
+        let dose = SimplifyOptions::default().weight(NamedUnit::Gray, 2);
+        assert_eq!("Sv", derived::GRAY.simplify_with(&dose).to_string());
+    }
+}
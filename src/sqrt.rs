@@ -1,6 +1,113 @@
 use crate::base::BaseValue;
 use crate::derived::DerivedValue;
-use crate::exponents::UnitExponent;
+use crate::exponents::{FractionalExponent, UnitError, UnitExponent};
+
+/// Number operations for raising values to powers and taking their roots
+/// which return `None` if the result can't be represented.
+trait RootNumber: Sized {
+    fn checked_powi(self, power: i8) -> Option<Self>;
+
+    /// Integer roots round towards zero like `isqrt`.
+    fn checked_nth_root(self, root: i8) -> Option<Self>;
+
+    /// Raises the number to the power `numerator / denominator`.
+    fn checked_pow_ratio(self, numerator: i8, denominator: i8) -> Option<Self> {
+        self.checked_powi(numerator)?.checked_nth_root(denominator)
+    }
+}
+
+macro_rules! impl_root_number_float {
+    ($type:ident) => {
+        impl RootNumber for $type {
+            fn checked_powi(self, power: i8) -> Option<Self> {
+                Some(self.powi(power as i32))
+            }
+
+            fn checked_nth_root(self, root: i8) -> Option<Self> {
+                match root {
+                    0 => None,
+                    2 => Some(self.sqrt()),
+                    3 => Some(self.cbrt()),
+                    // Odd roots of negative numbers are real
+                    _ if self < 0.0 && root % 2 != 0 => Some(-(-self).powf(1.0 / root as $type)),
+                    _ => Some(self.powf(1.0 / root as $type)),
+                }
+            }
+
+            fn checked_pow_ratio(self, numerator: i8, denominator: i8) -> Option<Self> {
+                // Root first so odd roots of negative numbers stay real
+                self.checked_nth_root(denominator)?.checked_powi(numerator)
+            }
+        }
+    };
+}
+
+/// Only 1 and -1 have integer reciprocals
+macro_rules! int_recip {
+    ($type:ident, $value:expr) => {{
+        let value = $value;
+        (1 as $type).checked_div(value).filter(|recip| recip.wrapping_mul(value) == 1)
+    }};
+}
+
+macro_rules! impl_root_number_unsigned {
+    ($type:ident) => {
+        impl RootNumber for $type {
+            fn checked_powi(self, power: i8) -> Option<Self> {
+                let magnitude = self.checked_pow(power.unsigned_abs() as u32)?;
+                if power < 0 { int_recip!($type, magnitude) } else { Some(magnitude) }
+            }
+
+            fn checked_nth_root(self, root: i8) -> Option<Self> {
+                let exponent = root.checked_abs().filter(|n| *n != 0)? as u32;
+                // Binary search for the largest number whose power doesn't exceed self
+                let (mut low, mut high) = (0 as $type, self);
+                while low < high {
+                    let mid = low + (high - low).div_ceil(2);
+                    match mid.checked_pow(exponent) {
+                        Some(power) if power <= self => low = mid,
+                        _ => high = mid - 1,
+                    }
+                }
+                if root < 0 { int_recip!($type, low) } else { Some(low) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_root_number_signed {
+    ($type:ident) => {
+        impl RootNumber for $type {
+            fn checked_powi(self, power: i8) -> Option<Self> {
+                let magnitude = self.checked_pow(power.unsigned_abs() as u32)?;
+                if power < 0 { int_recip!($type, magnitude) } else { Some(magnitude) }
+            }
+
+            fn checked_nth_root(self, root: i8) -> Option<Self> {
+                if self < 0 && root % 2 == 0 {
+                    return None;
+                }
+                let magnitude = self.unsigned_abs().checked_nth_root(root.checked_abs()?)?;
+                let magnitude = Self::try_from(magnitude).ok()?;
+                let root_value = if self < 0 { -magnitude } else { magnitude };
+                if root < 0 { int_recip!($type, root_value) } else { Some(root_value) }
+            }
+        }
+    };
+}
+
+impl_root_number_float!(f32);
+impl_root_number_float!(f64);
+
+impl_root_number_unsigned!(u8);
+impl_root_number_unsigned!(u16);
+impl_root_number_unsigned!(u32);
+impl_root_number_unsigned!(u64);
+
+impl_root_number_signed!(i8);
+impl_root_number_signed!(i16);
+impl_root_number_signed!(i32);
+impl_root_number_signed!(i64);
 
 macro_rules! impl_sqrt {
     ($type:ident, $sqrt:ident) => {
@@ -16,6 +123,79 @@ macro_rules! impl_sqrt {
                 number: self.number.$sqrt()
               }
             }
+
+            /// The non-panicking version of `sqrt`, which also fails
+            /// if the number has no square root (e.g. negative integers).
+            pub fn checked_sqrt(self) -> Result<Self, UnitError> {
+                self.checked_root(2)
+            }
+
+            /// Raises the value and its unit to an integer power.
+            /// Fails if either the new number or unit cannot be expressed.
+            pub fn checked_pow(self, power: i8) -> Result<Self, UnitError> {
+                Ok(Self {
+                    unit: self.unit.checked_pow(power)?,
+                    number: RootNumber::checked_powi(self.number, power)
+                        .ok_or(UnitError::UnrepresentableNumber)?,
+                })
+            }
+
+            /// Computes the nth root of a value and its unit.
+            /// Fails if either the new number or unit cannot be expressed.
+            pub fn checked_root(self, root: i8) -> Result<Self, UnitError> {
+                Ok(Self {
+                    unit: self.unit.checked_root(root)?,
+                    number: RootNumber::checked_nth_root(self.number, root)
+                        .ok_or(UnitError::UnrepresentableNumber)?,
+                })
+            }
+
+            /// Raises the value and its unit to an integer power.
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn powi(self, power: i8) -> Self {
+                self.checked_pow(power).unwrap_or_else(|error| panic!("{error}"))
+            }
+
+            /// Computes the cube root of a value and its unit.
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn cbrt(self) -> Self {
+                self.nth_root(3)
+            }
+
+            /// The non-panicking version of `cbrt`.
+            pub fn checked_cbrt(self) -> Result<Self, UnitError> {
+                self.checked_root(3)
+            }
+
+            /// Computes the nth root of a value and its unit.
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn nth_root(self, root: i8) -> Self {
+                self.checked_root(root).unwrap_or_else(|error| panic!("{error}"))
+            }
+
+            /// Raises the value and its unit to a fractional power (e.g. 3/2).
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn powf_exponent(self, exponent: FractionalExponent) -> Self {
+                self.checked_powf_exponent(exponent)
+                    .unwrap_or_else(|error| panic!("{error}"))
+            }
+
+            /// The non-panicking version of `powf_exponent`.
+            pub fn checked_powf_exponent(self, exponent: FractionalExponent) -> Result<Self, UnitError> {
+                // Reduce the fraction so intermediate exponents stay small
+                let quarters = exponent.quarters();
+                let divisor = match quarters % 4 {
+                    0 => 4,
+                    2 | -2 => 2,
+                    _ => 1,
+                };
+                let (numerator, denominator) = (quarters / divisor, 4 / divisor);
+                Ok(Self {
+                    unit: self.unit.checked_pow(numerator)?.checked_root(denominator)?,
+                    number: RootNumber::checked_pow_ratio(self.number, numerator, denominator)
+                        .ok_or(UnitError::UnrepresentableNumber)?,
+                })
+            }
         }
 
         impl<ExponentType> DerivedValue<$type, ExponentType>
@@ -30,6 +210,79 @@ macro_rules! impl_sqrt {
                 number: self.number.$sqrt()
               }
             }
+
+            /// The non-panicking version of `sqrt`, which also fails
+            /// if the number has no square root (e.g. negative integers).
+            pub fn checked_sqrt(self) -> Result<Self, UnitError> {
+                self.checked_root(2)
+            }
+
+            /// Raises the value and its unit to an integer power.
+            /// Fails if either the new number or unit cannot be expressed.
+            pub fn checked_pow(self, power: i8) -> Result<Self, UnitError> {
+                Ok(Self {
+                    unit: self.unit.checked_pow(power)?,
+                    number: RootNumber::checked_powi(self.number, power)
+                        .ok_or(UnitError::UnrepresentableNumber)?,
+                })
+            }
+
+            /// Computes the nth root of a value and its unit.
+            /// Fails if either the new number or unit cannot be expressed.
+            pub fn checked_root(self, root: i8) -> Result<Self, UnitError> {
+                Ok(Self {
+                    unit: self.unit.checked_root(root)?,
+                    number: RootNumber::checked_nth_root(self.number, root)
+                        .ok_or(UnitError::UnrepresentableNumber)?,
+                })
+            }
+
+            /// Raises the value and its unit to an integer power.
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn powi(self, power: i8) -> Self {
+                self.checked_pow(power).unwrap_or_else(|error| panic!("{error}"))
+            }
+
+            /// Computes the cube root of a value and its unit.
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn cbrt(self) -> Self {
+                self.nth_root(3)
+            }
+
+            /// The non-panicking version of `cbrt`.
+            pub fn checked_cbrt(self) -> Result<Self, UnitError> {
+                self.checked_root(3)
+            }
+
+            /// Computes the nth root of a value and its unit.
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn nth_root(self, root: i8) -> Self {
+                self.checked_root(root).unwrap_or_else(|error| panic!("{error}"))
+            }
+
+            /// Raises the value and its unit to a fractional power (e.g. 3/2).
+            /// Fails if either the new value or unit cannot be expressed.
+            pub fn powf_exponent(self, exponent: FractionalExponent) -> Self {
+                self.checked_powf_exponent(exponent)
+                    .unwrap_or_else(|error| panic!("{error}"))
+            }
+
+            /// The non-panicking version of `powf_exponent`.
+            pub fn checked_powf_exponent(self, exponent: FractionalExponent) -> Result<Self, UnitError> {
+                // Reduce the fraction so intermediate exponents stay small
+                let quarters = exponent.quarters();
+                let divisor = match quarters % 4 {
+                    0 => 4,
+                    2 | -2 => 2,
+                    _ => 1,
+                };
+                let (numerator, denominator) = (quarters / divisor, 4 / divisor);
+                Ok(Self {
+                    unit: self.unit.checked_pow(numerator)?.checked_root(denominator)?,
+                    number: RootNumber::checked_pow_ratio(self.number, numerator, denominator)
+                        .ok_or(UnitError::UnrepresentableNumber)?,
+                })
+            }
         }
     };
 }
@@ -55,9 +308,6 @@ mod tests {
   type FracUnit = BaseUnit<FractionalExponent>;
 
   fn test_sqrts(input: u8, input_unit: FracUnit, output: u8, output_unit: FracUnit) {
-    let input_unit = input_unit.into();
-    let output_unit = output_unit.into();
-
     let input_f32 = BaseValue { unit: input_unit, number: input as f32 };
     let output_f32 = BaseValue { unit: output_unit, number: output as f32 };
     assert_eq!(output_f32, input_f32.sqrt());
@@ -66,8 +316,8 @@ mod tests {
     let output_f64 = BaseValue { unit: output_unit, number: output as f64 };
     assert_eq!(output_f64, input_f64.sqrt());
 
-    let input_u8 = BaseValue { unit: input_unit, number: input as u8 };
-    let output_u8 = BaseValue { unit: output_unit, number: output as u8 };
+    let input_u8 = BaseValue { unit: input_unit, number: input };
+    let output_u8 = BaseValue { unit: output_unit, number: output };
     assert_eq!(output_u8, input_u8.sqrt());
   }
 
@@ -76,6 +326,81 @@ mod tests {
     test_sqrts(100, (base::METER * base::METER).into(), 10, base::METER.into());
     let meters: FracUnit = base::METER.into();
     test_sqrts(4, base::METER.into(), 2, meters.root(2));
+  }
+
+  #[test]
+  #[should_panic]
+  fn integer_root_of_meter() {
+    // √m has no integer exponent, so the root has to be taken after converting to `FracUnit`
     test_sqrts(4, base::METER.into(), 2, base::METER.root(2).into());
   }
-}
\ No newline at end of file
+
+  #[test]
+  fn checked_roots() {
+    let area = BaseValue { unit: base::METER_SQ, number: 16i32 };
+    let length = BaseValue { unit: base::METER, number: 4i32 };
+    assert_eq!(Ok(length), area.checked_sqrt());
+    assert_eq!(Ok(area), length.checked_pow(2));
+    assert_eq!(Err(UnitError::InexactRoot { root: 2 }), length.checked_sqrt());
+    assert_eq!(Err(UnitError::InexactRoot { root: 2 }), base::METER.checked_root(2));
+    assert_eq!(Err(UnitError::DivisionByZero), area.checked_root(0));
+
+    let negative_area = BaseValue { unit: base::METER_SQ, number: -16i32 };
+    assert_eq!(Err(UnitError::UnrepresentableNumber), negative_area.checked_sqrt());
+    assert_eq!(Err(UnitError::UnrepresentableNumber), length.checked_pow(-1));
+    let big_length = BaseValue { unit: base::METER, number: 100u8 };
+    assert_eq!(Err(UnitError::UnrepresentableNumber), big_length.checked_pow(2));
+
+    let volume = DerivedValue { unit: crate::derived::METER.pow(3), number: -27.0f64 };
+    let length = DerivedValue { unit: crate::derived::METER, number: -3.0f64 };
+    assert_eq!(Ok(length), volume.checked_root(3));
+    assert_eq!(Ok(DerivedValue { unit: crate::derived::METER.pow(-3), number: -1.0 / 27.0 }), length.checked_pow(-3));
+
+    assert_eq!(Some(3), RootNumber::checked_nth_root(30u8, 3));
+    assert_eq!(Some(-3), RootNumber::checked_nth_root(-30i64, 3));
+    assert_eq!(Some(11), RootNumber::checked_nth_root(i8::MAX, 2));
+    assert_eq!(Some(-2), RootNumber::checked_nth_root(i8::MIN, 7));
+    assert_eq!(None, RootNumber::checked_nth_root(i8::MIN, 1));
+    assert_eq!(Some(1), RootNumber::checked_nth_root(1u32, -2));
+  }
+
+  #[test]
+  fn powers_and_roots() {
+    use crate::{derived, exponents::ue};
+
+    let length = DerivedValue { unit: derived::METER, number: 2.0f64 };
+    let volume = DerivedValue { unit: derived::METER.pow(3), number: 8.0f64 };
+    assert_eq!(volume, length.powi(3));
+    assert_eq!(length, volume.cbrt());
+    assert_eq!(length, volume.powi(4).nth_root(12));
+    assert_eq!(Err(UnitError::InexactRoot { root: 3 }), length.checked_cbrt());
+
+    // Integers
+    let area = BaseValue { unit: base::METER_SQ, number: 16u32 };
+    assert_eq!(BaseValue { unit: base::METER.pow(3), number: 64 }, area.powf_exponent(ue(3) / 2));
+    assert_eq!(BaseValue { unit: base::METER_SQ.pow(2), number: 256 }, area.powi(2));
+    assert_eq!(
+      Err(UnitError::InexactRoot { root: 4 }),
+      area.checked_powf_exponent(ue(1) / 4)
+    );
+
+    // Fractional exponents
+    let area = BaseValue { unit: FracUnit::from(base::METER_SQ), number: 16.0f32 };
+    let root_length = BaseValue { unit: FracUnit::from(base::METER).root(2), number: 2.0f32 };
+    assert_eq!(root_length, area.powf_exponent(ue(1) / 4));
+    assert_eq!(
+      BaseValue { unit: FracUnit::from(base::METER).pow(3), number: 64.0 },
+      area.powf_exponent(ue(3) / 2)
+    );
+    assert_eq!(
+      BaseValue { unit: FracUnit::from(base::METER).pow(-3), number: 1.0 / 64.0 },
+      area.powf_exponent(-(ue(3) / 2))
+    );
+
+    // Negative numbers with odd roots
+    assert_eq!(Some(4.0), (-8.0f64).checked_pow_ratio(2, 3));
+    assert_eq!(Some(-0.5), (-8.0f32).checked_pow_ratio(-1, 3));
+    assert_eq!(Some(-32.0), (-8.0f64).checked_pow_ratio(5, 3));
+    assert!((-8.0f64).checked_pow_ratio(3, 2).unwrap().is_nan());
+  }
+}
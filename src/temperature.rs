@@ -0,0 +1,188 @@
+//! Absolute temperatures on affine scales (e.g. degrees Celsius and Fahrenheit)
+//! which are kept apart from temperature differences.
+//!
+//! A `Temperature` is a point on the thermodynamic temperature scale, stored in kelvin,
+//! while a difference between two temperatures is an ordinary `DerivedValue` in `K`.
+//! Subtracting two temperatures gives a difference and adding a difference to a temperature
+//! gives a temperature, but two temperatures can't be added.
+//!
+//! ```
+//! use physical_units::{derived::DerivedValue, scaled, temperature::Temperature};
+//!
+//! let inside = Temperature::from_unit(21.0f64, &scaled::DEGREE_CELSIUS).unwrap();
+//! let outside = Temperature::from_unit(50.0, &scaled::DEGREE_FAHRENHEIT).unwrap();
+//! let difference = inside - outside;
+//! assert!((difference.number() - 11.0).abs() < 1e-12);
+//!
+//! let warmer = (inside + DerivedValue::kelvin(2.0)).unwrap();
+//! assert!((warmer.in_unit(&scaled::DEGREE_CELSIUS).unwrap() - 23.0).abs() < 1e-12);
+//! ```
+//!
+//! Adding two temperatures doesn't compile:
+//!
+//! ```compile_fail
+//! use physical_units::temperature::Temperature;
+//!
+//! let sum = Temperature::from_kelvin(300.0) + Temperature::from_kelvin(280.0);
+//! ```
+//!
+//! Differences are converted with `ScaledUnit::without_offset`
+//! since a change of 1 °C is a change of 1 K (and 1.8 °F), not 274.15 K.
+
+use core::fmt;
+use std::ops::{Add, Sub};
+
+use crate::{
+    derived::{self, DerivedValue, UnitMismatch},
+    scaled::{ScalableNumber, ScaledUnit, ScaledUnitMismatch},
+};
+
+/// An absolute temperature, stored in kelvin.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Temperature<Number> {
+    kelvin: Number,
+}
+
+impl<Number> Temperature<Number> {
+    pub const fn from_kelvin(kelvin: Number) -> Self {
+        Self { kelvin }
+    }
+
+    /// The temperature in kelvin.
+    pub const fn kelvin(&self) -> &Number {
+        &self.kelvin
+    }
+
+    /// The temperature as a value in `K`, measured from absolute zero.
+    pub fn into_value(self) -> DerivedValue<Number> {
+        DerivedValue::new(self.kelvin, derived::KELVIN)
+    }
+
+    /// Treats a value in `K` as measured from absolute zero, failing if it isn't in kelvin.
+    pub fn from_value(value: DerivedValue<Number>) -> Result<Self, UnitMismatch<i8>> {
+        if value.unit != derived::KELVIN {
+            return Err(UnitMismatch {
+                lhs: value.unit,
+                rhs: derived::KELVIN,
+            });
+        }
+        Ok(Self {
+            kelvin: value.number,
+        })
+    }
+}
+
+impl<Number: ScalableNumber> Temperature<Number> {
+    /// A temperature read on a scale (e.g. 21 °C),
+    /// failing if the unit isn't a multiple of the kelvin.
+    pub fn from_unit(number: Number, unit: &ScaledUnit) -> Result<Self, ScaledUnitMismatch> {
+        Self::from_value(DerivedValue::from_unit(number, unit)).map_err(|_| ScaledUnitMismatch {
+            unit: derived::KELVIN,
+            scaled: unit.symbol.clone(),
+            expected: unit.unit,
+        })
+    }
+
+    /// The reading of this temperature on a scale (e.g. 294.15 K is 21 °C).
+    pub fn in_unit(&self, unit: &ScaledUnit) -> Result<Number, ScaledUnitMismatch> {
+        DerivedValue::new(self.kelvin, derived::KELVIN).in_unit(unit)
+    }
+}
+
+impl<Number> fmt::Display for Temperature<Number>
+where
+    Number: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} K", self.kelvin)
+    }
+}
+
+/// The difference between two temperatures, in `K`.
+impl<Number> Sub for Temperature<Number>
+where
+    Number: Sub<Output = Number>,
+{
+    type Output = DerivedValue<Number>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        DerivedValue::new(self.kelvin - rhs.kelvin, derived::KELVIN)
+    }
+}
+
+/// Adds a temperature difference, failing if it isn't in `K`.
+impl<Number> Add<DerivedValue<Number>> for Temperature<Number>
+where
+    Number: Add<Output = Number>,
+{
+    type Output = Result<Self, UnitMismatch<i8>>;
+
+    fn add(self, rhs: DerivedValue<Number>) -> Self::Output {
+        let rhs = Self::from_value(rhs)?;
+        Ok(Self {
+            kelvin: self.kelvin + rhs.kelvin,
+        })
+    }
+}
+
+/// Subtracts a temperature difference, failing if it isn't in `K`.
+impl<Number> Sub<DerivedValue<Number>> for Temperature<Number>
+where
+    Number: Sub<Output = Number>,
+{
+    type Output = Result<Self, UnitMismatch<i8>>;
+
+    fn sub(self, rhs: DerivedValue<Number>) -> Self::Output {
+        let rhs = Self::from_value(rhs)?;
+        Ok(Self {
+            kelvin: self.kelvin - rhs.kelvin,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scaled::{DEGREE_CELSIUS, DEGREE_FAHRENHEIT, DEGREE_RANKINE, HOUR};
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!((expected - actual).abs() < 1e-9, "{expected} != {actual}");
+    }
+
+    #[test]
+    fn test_conversions() {
+        let freezing = Temperature::from_unit(32.0, &DEGREE_FAHRENHEIT).unwrap();
+        assert_close(273.15, *freezing.kelvin());
+        assert_close(0.0, freezing.in_unit(&DEGREE_CELSIUS).unwrap());
+        assert_close(491.67, freezing.in_unit(&DEGREE_RANKINE).unwrap());
+        let body = Temperature::from_unit(37.0, &DEGREE_CELSIUS).unwrap();
+        assert_close(98.6, body.in_unit(&DEGREE_FAHRENHEIT).unwrap());
+        assert_eq!("310.15 K", body.to_string());
+
+        let error = Temperature::from_unit(1.0, &HOUR).unwrap_err();
+        assert_eq!(
+            "Unit 'K' can't be converted to 'h' which is a multiple of 's'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_affine_arithmetic() {
+        let morning = Temperature::from_unit(15.0, &DEGREE_CELSIUS).unwrap();
+        let noon = Temperature::from_unit(77.0, &DEGREE_FAHRENHEIT).unwrap();
+        let rise = noon - morning;
+        assert_eq!(derived::KELVIN, rise.unit);
+        assert_close(10.0, rise.number);
+        assert_close(
+            18.0,
+            rise.in_unit(&DEGREE_FAHRENHEIT.without_offset()).unwrap(),
+        );
+
+        let evening = (noon - DerivedValue::kelvin(5.0)).unwrap();
+        assert_close(20.0, evening.in_unit(&DEGREE_CELSIUS).unwrap());
+        assert_eq!(Ok(noon), morning + rise);
+
+        let error = (morning + DerivedValue::meters(1.0)).unwrap_err();
+        assert_eq!("Unit 'm' didn't match 'K'", error.to_string());
+    }
+}